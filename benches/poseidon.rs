@@ -0,0 +1,163 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion, Throughput};
+use digest::{FixedOutput, Update};
+use halo2curves_axiom::bn256::Fr;
+use halo2curves_axiom::group::ff::Field;
+use pse_poseidon::merkle::merkle_level_in_place;
+use pse_poseidon::{poseidon2to1, Poseidon, Spec};
+use rand_core::OsRng;
+
+const R_F: usize = 8;
+const R_P: usize = 57;
+const T: usize = 5;
+const RATE: usize = 4;
+
+fn gen_random_vec(len: usize) -> Vec<Fr> {
+    (0..len).map(|_| Fr::random(OsRng)).collect::<Vec<Fr>>()
+}
+
+fn shared_prefix(c: &mut Criterion) {
+    let spec = Spec::<Fr, T, RATE>::new(R_F, R_P);
+    let hasher = Poseidon::from_spec(spec.clone());
+    let prefix = gen_random_vec(8 * RATE);
+    let suffixes = (0..16)
+        .map(|_| gen_random_vec(RATE))
+        .collect::<Vec<Vec<Fr>>>();
+
+    let mut group = c.benchmark_group("shared_prefix");
+    group.bench_function("independent", |b| {
+        b.iter(|| {
+            suffixes
+                .iter()
+                .map(|suffix| {
+                    let mut hasher = hasher.clone();
+                    hasher.update(black_box(&prefix));
+                    hasher.update(black_box(suffix));
+                    hasher.squeeze()
+                })
+                .collect::<Vec<Fr>>()
+        })
+    });
+    group.bench_function("shared", |b| {
+        b.iter(|| {
+            Poseidon::hash_batch_shared_prefix(black_box(&prefix), black_box(&suffixes), &spec)
+        })
+    });
+    group.finish();
+}
+
+fn tag_range(c: &mut Criterion) {
+    let spec = Spec::<Fr, 3, 2>::new(R_F, R_P);
+    let base = Fr::random(OsRng);
+    let n = 64;
+
+    let mut group = c.benchmark_group("tag_range");
+    group.bench_function("hash_two", |b| {
+        b.iter(|| {
+            (0..n)
+                .map(|i| spec.hash_two(black_box(base), Fr::from(i as u64)))
+                .collect::<Vec<Fr>>()
+        })
+    });
+    group.bench_function("tag_range", |b| {
+        b.iter(|| spec.tag_range(black_box(base), n))
+    });
+    group.finish();
+}
+
+fn update_bytes(c: &mut Criterion) {
+    let bytes = (0..4096).map(|i| i as u8).collect::<Vec<u8>>();
+    let hasher = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+
+    // Absorbing each byte as an element takes 1025 permutations for 4096
+    // bytes, packing 31 bytes per element takes 34
+    let mut group = c.benchmark_group("update_bytes");
+    group.throughput(Throughput::Bytes(bytes.len() as u64));
+    group.bench_function("per_byte", |b| {
+        b.iter(|| {
+            let mut hasher = hasher.clone();
+            let elements = black_box(&bytes)
+                .iter()
+                .map(|byte| Fr::from(*byte as u64))
+                .collect::<Vec<Fr>>();
+            hasher.update(&elements);
+            hasher.squeeze()
+        })
+    });
+    group.bench_function("packed", |b| {
+        b.iter(|| {
+            let mut hasher = hasher.clone();
+            Update::update(&mut hasher, black_box(&bytes));
+            hasher.finalize_fixed()
+        })
+    });
+    group.finish();
+}
+
+fn two_to_one(c: &mut Criterion) {
+    let spec = Spec::<Fr, 3, 2>::new(R_F, R_P);
+    let (a, b) = (Fr::random(OsRng), Fr::random(OsRng));
+
+    let mut group = c.benchmark_group("two_to_one");
+    group.bench_function("hash_two", |bench| {
+        bench.iter(|| spec.hash_two(black_box(a), black_box(b)))
+    });
+    group.bench_function("poseidon2to1", |bench| {
+        bench.iter(|| poseidon2to1(black_box(a), black_box(b)))
+    });
+    group.finish();
+}
+
+fn merkle_level(c: &mut Criterion) {
+    let spec = Spec::<Fr, 3, 2>::new(R_F, R_P);
+    let nodes = gen_random_vec(256);
+
+    let mut group = c.benchmark_group("merkle_level");
+    group.bench_function("allocating", |b| {
+        b.iter(|| {
+            black_box(&nodes)
+                .chunks(2)
+                .map(|pair| spec.hash_two(pair[0], pair[1]))
+                .collect::<Vec<Fr>>()
+        })
+    });
+    group.bench_function("in_place", |b| {
+        b.iter_batched_ref(
+            || nodes.clone(),
+            |nodes| merkle_level_in_place(nodes, &spec),
+            BatchSize::SmallInput,
+        )
+    });
+    group.finish();
+}
+
+fn hash_batch(c: &mut Criterion) {
+    let spec = Spec::<Fr, T, RATE>::new(R_F, R_P);
+    let inputs = (0..16)
+        .map(|_| gen_random_vec(RATE - 1))
+        .collect::<Vec<Vec<Fr>>>();
+
+    let mut group = c.benchmark_group("hash_batch");
+    group.bench_function("independent", |b| {
+        b.iter(|| {
+            black_box(&inputs)
+                .iter()
+                .map(|inputs| Poseidon::<Fr, T, RATE>::hash_tagged(inputs, R_F, R_P))
+                .collect::<Vec<Fr>>()
+        })
+    });
+    group.bench_function("batch", |b| {
+        b.iter(|| Poseidon::hash_batch(black_box(&inputs), &spec))
+    });
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    shared_prefix,
+    tag_range,
+    update_bytes,
+    two_to_one,
+    merkle_level,
+    hash_batch
+);
+criterion_main!(benches);
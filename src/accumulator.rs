@@ -0,0 +1,169 @@
+use crate::{Spec, State};
+use halo2curves_axiom::group::ff::{FromUniformBytes, PrimeField};
+
+/// `LinearAccumulator` commits to a vector as the field sum of
+/// `hash_two(index, value)` over its positions. Since the commitment is
+/// additive, changing a single position is done by subtracting the old term
+/// and adding the new one without rehashing the rest of the vector.
+///
+/// Note that this is not the sponge construction and has weaker properties.
+/// Finding two vectors with the same sum is a generalized birthday problem
+/// which is much easier than finding a collision of the hash itself, so it
+/// must not be used where collision resistance of the sponge is expected.
+#[derive(Debug, Clone)]
+pub struct LinearAccumulator<F: PrimeField, const T: usize, const RATE: usize> {
+    spec: Spec<F, T, RATE>,
+    value: F,
+}
+
+impl<F: FromUniformBytes<64>, const T: usize, const RATE: usize> LinearAccumulator<F, T, RATE> {
+    /// Constructs an accumulator committing to the empty vector
+    pub fn new(r_f: usize, r_p: usize) -> Self {
+        Self {
+            spec: Spec::new(r_f, r_p),
+            value: F::ZERO,
+        }
+    }
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize> LinearAccumulator<F, T, RATE> {
+    fn term(&self, index: usize, value: F) -> F {
+        self.spec.hash_two(F::from(index as u64), value)
+    }
+
+    /// Adds `value` at `index` to the commitment
+    pub fn insert(&mut self, index: usize, value: F) {
+        self.value += self.term(index, value);
+    }
+
+    /// Replaces `old` value at `index` with `new` one
+    pub fn update_delta(&mut self, index: usize, old: F, new: F) {
+        self.value += self.term(index, new) - self.term(index, old);
+    }
+
+    /// Returns the current commitment
+    pub fn value(&self) -> F {
+        self.value
+    }
+}
+
+/// `MultisetAccumulator` commits to a multiset as the field sum of element
+/// hashes, where an element is hashed as fixed length input of a single
+/// element. Adding and removing elements is done by adding and subtracting
+/// their hashes, so the commitment doesn't depend on the order of operations
+/// and multiplicities are counted modulo the field characteristic.
+///
+/// Like `LinearAccumulator` this is an additive construction. Security relies
+/// on the hardness of finding a nontrivial combination of element hashes
+/// summing to zero, which is a generalized birthday problem and much easier
+/// than finding a hash collision, so it is only suitable for small or non
+/// adversarial multisets unless parameters are chosen accordingly.
+#[derive(Debug, Clone)]
+pub struct MultisetAccumulator<F: PrimeField, const T: usize, const RATE: usize> {
+    spec: Spec<F, T, RATE>,
+    value: F,
+}
+
+impl<F: FromUniformBytes<64>, const T: usize, const RATE: usize> MultisetAccumulator<F, T, RATE> {
+    /// Constructs an accumulator committing to the empty multiset
+    pub fn new(r_f: usize, r_p: usize) -> Self {
+        Self {
+            spec: Spec::new(r_f, r_p),
+            value: F::ZERO,
+        }
+    }
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize> MultisetAccumulator<F, T, RATE> {
+    fn term(&self, element: F) -> F {
+        let mut state = State::fixed_length(1);
+        state.0[1] = element;
+        self.spec.permute(&mut state);
+        state.result()
+    }
+
+    /// Adds an occurrence of `element` to the multiset
+    pub fn add(&mut self, element: F) {
+        self.value += self.term(element);
+    }
+
+    /// Removes an occurrence of `element` from the multiset. Removing an
+    /// element that is not in the multiset is not detected
+    pub fn remove(&mut self, element: F) {
+        self.value -= self.term(element);
+    }
+
+    /// Returns the current commitment
+    pub fn value(&self) -> F {
+        self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LinearAccumulator, MultisetAccumulator};
+    use crate::Poseidon;
+    use halo2curves_axiom::bn256::Fr;
+    use halo2curves_axiom::group::ff::Field;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_linear_accumulator_update_delta() {
+        let commit = |values: &[Fr]| {
+            let mut acc = LinearAccumulator::<Fr, 3, 2>::new(8, 57);
+            for (i, value) in values.iter().enumerate() {
+                acc.insert(i, *value);
+            }
+            acc
+        };
+
+        let mut values = (0..8).map(|_| Fr::random(OsRng)).collect::<Vec<Fr>>();
+        let mut acc = commit(&values);
+
+        for index in [0, 3, 7] {
+            let new = Fr::random(OsRng);
+            acc.update_delta(index, values[index], new);
+            values[index] = new;
+            assert_eq!(acc.value(), commit(&values).value());
+        }
+
+        // Positions are bound
+        values.swap(1, 2);
+        assert_ne!(acc.value(), commit(&values).value());
+    }
+
+    #[test]
+    fn test_multiset_accumulator() {
+        let mut acc = MultisetAccumulator::<Fr, 3, 2>::new(8, 57);
+        let elements = (0..8).map(|_| Fr::random(OsRng)).collect::<Vec<Fr>>();
+        for element in elements.iter() {
+            acc.add(*element);
+        }
+        let value = acc.value();
+
+        // Add then remove returns to the original value
+        let element = Fr::random(OsRng);
+        acc.add(element);
+        assert_ne!(acc.value(), value);
+        acc.remove(element);
+        assert_eq!(acc.value(), value);
+
+        // Order doesn't matter but multiplicity does
+        let mut reordered = MultisetAccumulator::<Fr, 3, 2>::new(8, 57);
+        for element in elements.iter().rev() {
+            reordered.add(*element);
+        }
+        assert_eq!(reordered.value(), value);
+        reordered.add(elements[0]);
+        assert_ne!(reordered.value(), value);
+
+        // Element hash is the fixed length hash of the element
+        let mut acc = MultisetAccumulator::<Fr, 3, 2>::new(8, 57);
+        acc.add(element);
+        let mut poseidon = Poseidon::<Fr, 3, 2>::new_fixed_length(1, 8, 57);
+        poseidon.update(&[element]);
+        assert_eq!(acc.value(), poseidon.squeeze());
+        acc.remove(element);
+        assert_eq!(acc.value(), Fr::zero());
+    }
+}
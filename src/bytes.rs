@@ -0,0 +1,251 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use digest::generic_array::ArrayLength;
+use halo2curves_axiom::group::ff::PrimeField;
+use typenum::U32;
+
+/// Byte array used as `PrimeField::Repr` with its length as a type level
+/// integer. Implemented for arrays of common field sizes
+pub trait ReprBytes: AsRef<[u8]> {
+    /// Number of bytes in the array
+    type Size: ArrayLength<u8> + 'static;
+}
+
+macro_rules! impl_repr_bytes {
+    ($($len:literal => $size:ty),*) => {
+        $(
+            impl ReprBytes for [u8; $len] {
+                type Size = $size;
+            }
+        )*
+    };
+}
+
+impl_repr_bytes!(32 => typenum::U32, 48 => typenum::U48, 64 => typenum::U64);
+
+/// Length of the canonical byte representation of a field, used as the
+/// output size of the `digest` integrations. It is implemented for every
+/// field whose representation implements `ReprBytes`
+pub trait ReprSize: PrimeField {
+    /// Number of bytes in `PrimeField::Repr`
+    type Size: ArrayLength<u8> + 'static;
+}
+
+impl<F: PrimeField> ReprSize for F
+where
+    F::Repr: ReprBytes,
+{
+    type Size = <F::Repr as ReprBytes>::Size;
+}
+
+/// Number of bytes packed into a single field element. It is the largest
+/// number of bytes that always fits under the modulus
+pub(crate) fn bytes_per_element<F: PrimeField>() -> usize {
+    F::CAPACITY as usize / 8
+}
+
+/// Packs bytes into field elements interpreting each `bytes_per_element`
+/// sized chunk as a little endian integer. Last chunk may be shorter. Expects
+/// little endian field representation
+pub(crate) fn pack_bytes<F: PrimeField>(bytes: &[u8]) -> Vec<F> {
+    bytes
+        .chunks(bytes_per_element::<F>())
+        .map(|chunk| {
+            let mut repr = F::Repr::default();
+            repr.as_mut()[..chunk.len()].copy_from_slice(chunk);
+            F::from_repr(repr).unwrap()
+        })
+        .collect()
+}
+
+/// Packs bytes into field elements as in `pack_bytes` but interpreting each
+/// chunk as a big endian integer. Expects little endian field representation
+pub(crate) fn pack_bytes_be<F: PrimeField>(bytes: &[u8]) -> Vec<F> {
+    bytes
+        .chunks(bytes_per_element::<F>())
+        .map(|chunk| {
+            let mut repr = F::Repr::default();
+            for (byte, chunk_byte) in repr.as_mut().iter_mut().zip(chunk.iter().rev()) {
+                *byte = *chunk_byte;
+            }
+            F::from_repr(repr).unwrap()
+        })
+        .collect()
+}
+
+/// Writes the big endian representation of a field element into `out`.
+/// Panics if `out` is not exactly as long as the representation
+pub(crate) fn write_be<F: PrimeField>(element: &F, out: &mut [u8]) {
+    let repr = element.to_repr();
+    let bytes = repr.as_ref();
+    assert_eq!(
+        out.len(),
+        bytes.len(),
+        "output length doesn't match the field representation"
+    );
+    for (dst, src) in out.iter_mut().zip(bytes.iter().rev()) {
+        *dst = *src;
+    }
+}
+
+/// Returns the integer value of a field element as four 64 bit limbs in
+/// little endian order, that is the first limb is the least significant one.
+/// Field must have a 32 byte little endian representation
+pub fn to_limbs<F: ReprSize<Size = U32>>(f: F) -> [u64; 4] {
+    let repr = f.to_repr();
+    let bytes = repr.as_ref();
+    core::array::from_fn(|i| u64::from_le_bytes(bytes[8 * i..8 * (i + 1)].try_into().unwrap()))
+}
+
+/// Constructs a field element from four 64 bit limbs in little endian order
+/// as returned by `to_limbs`. Returns `None` if the value is not less than the
+/// modulus
+pub fn from_limbs<F: ReprSize<Size = U32>>(limbs: [u64; 4]) -> Option<F> {
+    let mut repr = F::Repr::default();
+    for (bytes, limb) in repr.as_mut().chunks_mut(8).zip(limbs) {
+        bytes.copy_from_slice(&limb.to_le_bytes());
+    }
+    F::from_repr(repr).into()
+}
+
+/// Returns the integer `lo + p * hi` as 64 little endian bytes where `p` is
+/// the modulus. For uniform `lo` and `hi` the integer is uniform in
+/// `[0, p^2)`. Field must have a 32 byte little endian representation
+pub(crate) fn wide_bytes<F: ReprSize<Size = U32>>(lo: F, hi: F) -> [u8; 64] {
+    let (lo, hi, modulus_minus_one) = (to_limbs(lo), to_limbs(hi), to_limbs(-F::ONE));
+
+    // hi * (p - 1) + hi + lo
+    let mut acc = [0u64; 8];
+    for (i, a) in hi.iter().enumerate() {
+        let mut carry = 0u128;
+        for (j, b) in modulus_minus_one.iter().enumerate() {
+            let t = acc[i + j] as u128 + *a as u128 * *b as u128 + carry;
+            acc[i + j] = t as u64;
+            carry = t >> 64;
+        }
+        acc[i + 4] = carry as u64;
+    }
+    for term in [hi, lo] {
+        let mut carry = 0u128;
+        for (i, limb) in acc.iter_mut().enumerate() {
+            let t = *limb as u128 + term.get(i).copied().unwrap_or(0) as u128 + carry;
+            *limb = t as u64;
+            carry = t >> 64;
+        }
+    }
+
+    let mut bytes = [0u8; 64];
+    for (chunk, limb) in bytes.chunks_mut(8).zip(acc) {
+        chunk.copy_from_slice(&limb.to_le_bytes());
+    }
+    bytes
+}
+
+/// Compares integer values of field elements. Expects little endian field
+/// representation
+pub(crate) fn cmp_canonical<F: PrimeField>(a: &F, b: &F) -> Ordering {
+    let (a, b) = (a.to_repr(), b.to_repr());
+    a.as_ref().iter().rev().cmp(b.as_ref().iter().rev())
+}
+
+/// Parses a big endian hex string with optional `0x` prefix. Returns `None`
+/// if the string is empty, has non hex characters or doesn't encode a
+/// canonical field element. Expects little endian field representation
+pub(crate) fn from_hex<F: PrimeField>(hex: &str) -> Option<F> {
+    let digits = hex.strip_prefix("0x").unwrap_or(hex);
+    let mut repr = F::Repr::default();
+    let bytes = repr.as_mut();
+    if digits.is_empty() || digits.len() > 2 * bytes.len() {
+        return None;
+    }
+    for (i, digit) in digits.chars().rev().enumerate() {
+        bytes[i / 2] |= (digit.to_digit(16)? as u8) << (4 * (i % 2));
+    }
+    F::from_repr(repr).into()
+}
+
+/// Encodes the element as a big endian hex string with `0x` prefix and all
+/// leading zeros. Inverse of `from_hex`
+pub(crate) fn to_hex<F: PrimeField>(e: &F) -> String {
+    let repr = e.to_repr();
+    let digits = repr
+        .as_ref()
+        .iter()
+        .rev()
+        .map(|byte| format!("{:02x}", byte))
+        .collect::<String>();
+    format!("0x{}", digits)
+}
+
+/// Initial register of `crc64`
+pub(crate) const CRC64_INIT: u64 = !0;
+
+/// Updates a CRC-64/XZ register with `bytes`. Register starts at
+/// `CRC64_INIT` and the checksum is the register xored with `!0`
+pub(crate) fn crc64(mut crc: u64, bytes: &[u8]) -> u64 {
+    const POLY: u64 = 0xc96c_5795_d787_0f42;
+    for byte in bytes.iter() {
+        crc ^= *byte as u64;
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (POLY & (crc & 1).wrapping_neg());
+        }
+    }
+    crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{crc64, from_limbs, to_limbs, write_be, CRC64_INIT};
+    use halo2curves_axiom::bn256::Fr;
+    use halo2curves_axiom::group::ff::{Field, PrimeField};
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_limbs() {
+        for _ in 0..100 {
+            let e = Fr::random(OsRng);
+            assert_eq!(from_limbs::<Fr>(to_limbs(e)), Some(e));
+        }
+        assert_eq!(to_limbs(Fr::from(5)), [5, 0, 0, 0]);
+        assert_eq!(to_limbs(Fr::from_u128(1 << 64)), [0, 1, 0, 0]);
+
+        let modulus = [
+            0x43e1f593f0000001,
+            0x2833e84879b97091,
+            0xb85045b68181585d,
+            0x30644e72e131a029,
+        ];
+        let mut modulus_minus_one = modulus;
+        modulus_minus_one[0] -= 1;
+        assert_eq!(from_limbs::<Fr>(modulus_minus_one), Some(-Fr::one()));
+        assert_eq!(from_limbs::<Fr>(modulus), None);
+        assert_eq!(from_limbs::<Fr>([u64::MAX; 4]), None);
+    }
+
+    #[test]
+    fn test_crc64() {
+        assert_eq!(crc64(CRC64_INIT, b"123456789") ^ !0, 0x995d_c9bb_df19_39fa);
+        assert_eq!(
+            crc64(crc64(CRC64_INIT, b"1234"), b"56789"),
+            crc64(CRC64_INIT, b"123456789")
+        );
+    }
+
+    #[test]
+    fn test_write_be() {
+        let e = Fr::from(0x0102_0304);
+        let be = e.to_repr().iter().rev().copied().collect::<Vec<u8>>();
+
+        let mut out = [0xff; 32];
+        write_be(&e, &mut out);
+        assert_eq!(out[..], be[..]);
+    }
+
+    #[test]
+    #[should_panic(expected = "output length")]
+    fn test_write_be_length_mismatch() {
+        write_be(&Fr::from(1), &mut [0; 48]);
+    }
+}
@@ -0,0 +1,209 @@
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+use halo2curves_axiom::group::ff::PrimeField;
+
+use crate::bytes::to_hex;
+use crate::{MDSMatrix, Spec};
+
+impl<F: PrimeField, const T: usize, const RATE: usize> Spec<F, T, RATE> {
+    /// Renders optimized constants in the layout of circomlib's
+    /// `poseidon_constants.circom`. Output has four functions that return
+    /// the arrays for `t == T`, elements are big endian hex with `0x` prefix:
+    ///
+    /// - `POSEIDON_C`: round constants of the first full rounds, then one per
+    ///   partial round, then the remaining full rounds, flattened
+    /// - `POSEIDON_S`: sparse matrices of partial rounds, each as its row
+    ///   followed by `col_hat`, flattened
+    /// - `POSEIDON_M`: transpose of the MDS matrix, since circomlib's `Mix`
+    ///   and `MixLast` compute `out[i] = sum(M[j][i] * in[j])` while the
+    ///   matrix here is applied as `out[i] = sum(M[i][j] * in[j])`
+    /// - `POSEIDON_P`: transpose of the matrix applied before the partial
+    ///   rounds
+    pub fn to_circom_constants(&self) -> String {
+        let constants = self
+            .constants
+            .start
+            .iter()
+            .flatten()
+            .chain(self.constants.partial.iter())
+            .chain(self.constants.end.iter().flatten())
+            .collect::<Vec<&F>>();
+        let sparse = self
+            .mds_matrices
+            .sparse_matrices
+            .iter()
+            .flat_map(|matrix| matrix.row().iter().chain(matrix.col_hat().iter()))
+            .collect::<Vec<&F>>();
+
+        [
+            circom_function("POSEIDON_C", T, &circom_vector(&constants, 2)),
+            circom_function("POSEIDON_S", T, &circom_vector(&sparse, 2)),
+            circom_function("POSEIDON_M", T, &circom_matrix(&self.mds_matrices.mds)),
+            circom_function(
+                "POSEIDON_P",
+                T,
+                &circom_matrix(&self.mds_matrices.pre_sparse_mds),
+            ),
+        ]
+        .join("\n")
+    }
+}
+
+fn indent(depth: usize) -> String {
+    "    ".repeat(depth)
+}
+
+fn circom_function(name: &str, t: usize, array: &str) -> String {
+    format!(
+        "function {}(t) {{\n{}if (t == {}) {{\n{}return {};\n{}}}\n}}\n",
+        name,
+        indent(1),
+        t,
+        indent(2),
+        array,
+        indent(1)
+    )
+}
+
+/// Renders an array with an element per line whose closing bracket is at the
+/// given depth
+fn circom_vector<F: PrimeField>(elements: &[&F], depth: usize) -> String {
+    let elements = elements
+        .iter()
+        .map(|e| format!("{}{}", indent(depth + 1), to_hex(*e)))
+        .collect::<Vec<String>>();
+    format!("[\n{}\n{}]", elements.join(",\n"), indent(depth))
+}
+
+/// Renders the transpose of the matrix as rows
+fn circom_matrix<F: PrimeField, const T: usize, const RATE: usize>(
+    matrix: &MDSMatrix<F, T, RATE>,
+) -> String {
+    let rows = matrix.rows();
+    let rows = (0..T)
+        .map(|i| {
+            let column = rows.iter().map(|row| &row[i]).collect::<Vec<&F>>();
+            format!("{}{}", indent(3), circom_vector(&column, 3))
+        })
+        .collect::<Vec<String>>();
+    format!("[\n{}\n{}]", rows.join(",\n"), indent(2))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bytes::from_hex;
+    use crate::{Spec, State};
+    use halo2curves_axiom::bn256::Fr;
+    use halo2curves_axiom::group::ff::Field;
+    use rand_core::OsRng;
+
+    /// Extracts the array returned by the function with the given name as
+    /// rows of elements. Vectors are returned as a single row
+    fn parse_array(source: &str, name: &str) -> Vec<Vec<Fr>> {
+        let body = source
+            .split(&format!("function {}(t)", name))
+            .nth(1)
+            .unwrap();
+        let array = body
+            .split("return")
+            .nth(1)
+            .unwrap()
+            .split(';')
+            .next()
+            .unwrap()
+            .trim();
+        let array = array.strip_prefix('[').unwrap().strip_suffix(']').unwrap();
+
+        let parse_row = |row: &str| {
+            row.split(',')
+                .map(|e| from_hex::<Fr>(e.trim()).unwrap())
+                .collect::<Vec<Fr>>()
+        };
+        if array.contains('[') {
+            array
+                .split(']')
+                .filter(|row| row.contains('['))
+                .map(|row| parse_row(row.split('[').nth(1).unwrap()))
+                .collect()
+        } else {
+            vec![parse_row(array)]
+        }
+    }
+
+    /// Permutes `state` the way circomlib's `PoseidonEx` does with the given
+    /// arrays, that is `Ark`, `Sigma`, `Mix`, `MixS` and `MixLast` in its
+    /// order
+    fn circom_permute(
+        state: &[Fr],
+        c: &[Fr],
+        s: &[Fr],
+        m: &[Vec<Fr>],
+        p: &[Vec<Fr>],
+        r_f: usize,
+        r_p: usize,
+    ) -> Vec<Fr> {
+        let t = state.len();
+        let sigma = |x: Fr| x.square().square() * x;
+        let ark = |state: &[Fr], offset: usize| {
+            (0..t)
+                .map(|i| state[i] + c[offset + i])
+                .collect::<Vec<Fr>>()
+        };
+        let mix = |state: &[Fr], m: &[Vec<Fr>]| {
+            (0..t)
+                .map(|i| (0..t).map(|j| m[j][i] * state[j]).sum())
+                .collect::<Vec<Fr>>()
+        };
+        let sigma_full = |state: &[Fr]| state.iter().map(|x| sigma(*x)).collect::<Vec<Fr>>();
+
+        let mut state = ark(state, 0);
+        for r in 0..r_f / 2 - 1 {
+            state = mix(&ark(&sigma_full(&state), (r + 1) * t), m);
+        }
+        state = mix(&ark(&sigma_full(&state), (r_f / 2) * t), p);
+        for r in 0..r_p {
+            state[0] = sigma(state[0]) + c[(r_f / 2 + 1) * t + r];
+            let row = &s[(2 * t - 1) * r..];
+            let first = (0..t).map(|i| row[i] * state[i]).sum();
+            let x0 = state[0];
+            for (word, col) in state.iter_mut().skip(1).zip(row[t..2 * t - 1].iter()) {
+                *word += x0 * col;
+            }
+            state[0] = first;
+        }
+        for r in 0..r_f / 2 - 1 {
+            let offset = (r_f / 2 + 1) * t + r_p + r * t;
+            state = mix(&ark(&sigma_full(&state), offset), m);
+        }
+        mix(&sigma_full(&state), m)
+    }
+
+    #[test]
+    fn test_to_circom_constants() {
+        const T: usize = 3;
+        for (r_f, r_p) in [(8, 57), (8, 56)] {
+            let spec = Spec::<Fr, T, 2>::new(r_f, r_p);
+            let source = spec.to_circom_constants();
+            assert_eq!(source.matches("if (t == 3)").count(), 4);
+
+            let c = parse_array(&source, "POSEIDON_C").remove(0);
+            let s = parse_array(&source, "POSEIDON_S").remove(0);
+            let m = parse_array(&source, "POSEIDON_M");
+            let p = parse_array(&source, "POSEIDON_P");
+            assert_eq!(c.len(), r_f * T + r_p);
+            assert_eq!(s.len(), (2 * T - 1) * r_p);
+
+            // Generated matrices are not symmetric so the order matters
+            assert_ne!(m[0][1], m[1][0]);
+
+            for _ in 0..4 {
+                let words = [(); T].map(|_| Fr::random(OsRng));
+                let mut state = State(words);
+                spec.permute(&mut state);
+                let expected = circom_permute(&words, &c, &s, &m, &p, r_f, r_p);
+                assert_eq!(state.words().to_vec(), expected);
+            }
+        }
+    }
+}
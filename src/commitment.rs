@@ -0,0 +1,108 @@
+use crate::Poseidon;
+use alloc::vec::Vec;
+use halo2curves_axiom::group::ff::{FromUniformBytes, PrimeField};
+
+/// `VectorCommitment` is a flat absorb based commitment to a vector. Each
+/// value is absorbed together with its index, so the commitment binds both
+/// values and their positions. Unlike a Merkle tree an opening has to carry all
+/// other values of the vector, so it is linear in the size of the vector.
+#[derive(Debug, Clone)]
+pub struct VectorCommitment<F: PrimeField, const T: usize, const RATE: usize> {
+    hasher: Poseidon<F, T, RATE>,
+    values: Vec<F>,
+}
+
+/// `VectorOpening` carries the values of the committed vector except the
+/// opened one
+#[derive(Debug, Clone, PartialEq)]
+pub struct VectorOpening<F: PrimeField> {
+    others: Vec<F>,
+}
+
+impl<F: FromUniformBytes<64>, const T: usize, const RATE: usize> VectorCommitment<F, T, RATE> {
+    /// Constructs a commitment scheme instance over given values
+    pub fn new(values: Vec<F>, r_f: usize, r_p: usize) -> Self {
+        Self {
+            hasher: Poseidon::new(r_f, r_p),
+            values,
+        }
+    }
+
+    fn commit_values(&self, values: &[F]) -> F {
+        let mut hasher = self.hasher.clone();
+        for (index, value) in values.iter().enumerate() {
+            hasher.update(&[F::from(index as u64), *value]);
+        }
+        hasher.squeeze()
+    }
+
+    /// Returns the commitment to the values as the hash of `(index, value)`
+    /// pairs in index order
+    pub fn commit(&self) -> F {
+        self.commit_values(&self.values)
+    }
+
+    /// Opens the value at `index`. Returns `None` if index is out of range
+    pub fn open(&self, index: usize) -> Option<VectorOpening<F>> {
+        (index < self.values.len()).then(|| {
+            let mut others = self.values.clone();
+            others.remove(index);
+            VectorOpening { others }
+        })
+    }
+
+    /// Checks that `value` is at `index` of the vector committed in
+    /// `commitment`. Only parameters of this instance are used so verifier may
+    /// construct it over an empty vector
+    pub fn verify(
+        &self,
+        commitment: F,
+        index: usize,
+        value: F,
+        opening: &VectorOpening<F>,
+    ) -> bool {
+        if index > opening.others.len() {
+            return false;
+        }
+        let mut values = opening.others.clone();
+        values.insert(index, value);
+        self.commit_values(&values).ct_eq(&commitment).into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VectorCommitment;
+    use halo2curves_axiom::bn256::Fr;
+    use halo2curves_axiom::group::ff::Field;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_vector_commitment() {
+        let values = (0..5).map(|_| Fr::random(OsRng)).collect::<Vec<Fr>>();
+        let prover = VectorCommitment::<Fr, 3, 2>::new(values.clone(), 8, 57);
+        let verifier = VectorCommitment::<Fr, 3, 2>::new(vec![], 8, 57);
+        let commitment = prover.commit();
+
+        assert!(prover.open(values.len()).is_none());
+        for (index, value) in values.iter().enumerate() {
+            let opening = prover.open(index).unwrap();
+            assert!(verifier.verify(commitment, index, *value, &opening));
+
+            // Wrong value
+            assert!(!verifier.verify(commitment, index, *value + Fr::one(), &opening));
+            // Wrong index
+            let other = (index + 1) % values.len();
+            assert!(!verifier.verify(commitment, other, *value, &opening));
+            assert!(!verifier.verify(commitment, values.len() + 1, *value, &opening));
+        }
+
+        // Positions are bound
+        let mut swapped = values.clone();
+        swapped.swap(0, 1);
+        assert_ne!(
+            commitment,
+            VectorCommitment::<Fr, 3, 2>::new(swapped, 8, 57).commit()
+        );
+    }
+}
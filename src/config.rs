@@ -0,0 +1,156 @@
+use crate::grain::Grain;
+use crate::spec::is_valid_alpha;
+use crate::{Error, Poseidon, Spec};
+use alloc::vec::Vec;
+use halo2curves_axiom::group::ff::{FromUniformBytes, PrimeField};
+
+/// `PoseidonConfig` bundles a complete parameterization, that is the width,
+/// rate, sbox exponent, number of rounds, unoptimized round constants and the
+/// MDS matrix. It is the canonical form to pin and share parameters, for
+/// example serialized with the `serde` feature
+#[derive(Debug, Clone, PartialEq)]
+pub struct PoseidonConfig<F: PrimeField> {
+    pub(crate) t: usize,
+    pub(crate) rate: usize,
+    pub(crate) alpha: u64,
+    pub(crate) r_f: usize,
+    pub(crate) r_p: usize,
+    pub(crate) round_constants: Vec<Vec<F>>,
+    pub(crate) mds: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField> PoseidonConfig<F> {
+    /// Width of the state
+    pub fn t(&self) -> usize {
+        self.t
+    }
+    /// Number of elements absorbed per permutation
+    pub fn rate(&self) -> usize {
+        self.rate
+    }
+    /// Exponent of the sbox
+    pub fn alpha(&self) -> u64 {
+        self.alpha
+    }
+    /// Number of full rounds
+    pub fn r_f(&self) -> usize {
+        self.r_f
+    }
+    /// Number of partial rounds
+    pub fn r_p(&self) -> usize {
+        self.r_p
+    }
+    /// Unoptimized round constants, `T` sized row for each round
+    pub fn round_constants(&self) -> &Vec<Vec<F>> {
+        &self.round_constants
+    }
+    /// `T x T` MDS matrix
+    pub fn mds(&self) -> &Vec<Vec<F>> {
+        &self.mds
+    }
+
+    /// Validates the parameters against `T` and `RATE` and constructs a clear
+    /// state hasher
+    pub fn build<const T: usize, const RATE: usize>(&self) -> Result<Poseidon<F, T, RATE>, Error> {
+        if self.t != T {
+            return Err(Error::InvalidConstantsShape {
+                expected: T,
+                actual: self.t,
+            });
+        }
+        if self.rate != RATE {
+            return Err(Error::InvalidConstantsShape {
+                expected: RATE,
+                actual: self.rate,
+            });
+        }
+
+        let spec = Spec::from_unoptimized_rows(
+            self.r_f,
+            self.r_p,
+            self.alpha,
+            &self.round_constants,
+            &self.mds,
+            |e: &F| Ok(*e),
+        )?;
+        Ok(Poseidon::from_spec(spec))
+    }
+}
+
+impl<F: FromUniformBytes<64>> PoseidonConfig<F> {
+    /// Generates round constants and the MDS matrix with reference `Grain`
+    /// as `Spec::new` does
+    pub fn generate<const T: usize, const RATE: usize>(
+        r_f: usize,
+        r_p: usize,
+        alpha: u64,
+    ) -> Result<Self, Error> {
+        if r_f == 0 || r_f % 2 == 1 {
+            return Err(Error::InvalidRounds { r_f });
+        }
+        if !is_valid_alpha::<F>(alpha) {
+            return Err(Error::InvalidAlpha { alpha });
+        }
+
+        let (round_constants, mds) = Grain::<F, T, RATE>::generate::<64>(r_f, r_p);
+        Ok(Self {
+            t: T,
+            rate: RATE,
+            alpha,
+            r_f,
+            r_p,
+            round_constants: round_constants.iter().map(|row| row.to_vec()).collect(),
+            mds: mds.rows().iter().map(|row| row.to_vec()).collect(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PoseidonConfig;
+    use crate::{Error, Poseidon};
+    use halo2curves_axiom::bn256::Fr;
+    use halo2curves_axiom::group::ff::Field;
+    use rand_core::OsRng;
+
+    const R_F: usize = 8;
+    const R_P: usize = 57;
+    const T: usize = 5;
+    const RATE: usize = 4;
+
+    #[test]
+    fn test_build_from_config() {
+        let config = PoseidonConfig::<Fr>::generate::<T, RATE>(R_F, R_P, 5).unwrap();
+        assert_eq!((config.t(), config.rate(), config.alpha()), (T, RATE, 5));
+        assert_eq!(config.round_constants().len(), R_F + R_P);
+
+        let inputs = (0..RATE + 1)
+            .map(|_| Fr::random(OsRng))
+            .collect::<Vec<Fr>>();
+        let mut poseidon = config.build::<T, RATE>().unwrap();
+        poseidon.update(&inputs);
+        let mut expected = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        expected.update(&inputs);
+        assert_eq!(poseidon.squeeze(), expected.squeeze());
+
+        let config_7 = PoseidonConfig::<Fr>::generate::<T, RATE>(R_F, R_P, 7).unwrap();
+        let mut poseidon = config_7.build::<T, RATE>().unwrap();
+        poseidon.update(&inputs);
+        assert_ne!(poseidon.squeeze(), expected.squeeze());
+
+        assert_eq!(
+            config.build::<3, 2>().unwrap_err(),
+            Error::InvalidConstantsShape {
+                expected: 3,
+                actual: T
+            }
+        );
+        assert_eq!(
+            PoseidonConfig::<Fr>::generate::<T, RATE>(R_F, R_P, 3).unwrap_err(),
+            Error::InvalidAlpha { alpha: 3 }
+        );
+        let mut invalid = config.clone();
+        invalid.mds[0][0] = Fr::zero();
+        assert_eq!(invalid.build::<T, RATE>().unwrap_err(), Error::InvalidMds);
+    }
+}
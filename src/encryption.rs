@@ -0,0 +1,141 @@
+use alloc::vec::Vec;
+use halo2curves_axiom::group::ff::PrimeField;
+
+use crate::{Error, Spec, State};
+
+/// Domain tag added to the capacity of the encryption state. It is the ASCII
+/// string `encrypt` read as a big endian integer, and since it is below
+/// `2**63` the capacity never collides with the one of a fixed length hash
+const ENCRYPTION_TAG: u64 = u64::from_be_bytes(*b"\0encrypt");
+
+/// Compile time check that the rate fits the key and the nonce
+struct EncryptionRate<const RATE: usize>;
+
+impl<const RATE: usize> EncryptionRate<RATE> {
+    const CHECK: () = assert!(RATE >= 2, "rate must fit the key and the nonce");
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize> Spec<F, T, RATE> {
+    /// Encrypts `plaintext` with the duplex sponge and returns the ciphertext
+    /// and the authentication tag. Capacity is initialized to
+    /// the fixed length capacity of `plaintext.len()` plus an encryption
+    /// domain tag and `key`,
+    /// `nonce` are absorbed with the first permutation. Then each chunk of
+    /// `RATE` elements is added to the rate, the resulting rate elements are
+    /// output as ciphertext and the state is permuted. Tag is the first rate
+    /// element of the final state.
+    ///
+    /// Security relies on the key being secret and uniformly random and on a
+    /// nonce that is never reused with the same key, reusing it reveals
+    /// differences of plaintexts. Length of the plaintext is not hidden and
+    /// the scheme is not key committing, a ciphertext may be decryptable under
+    /// different keys. Rate is checked at compile time to be at least 2
+    ///
+    /// ```compile_fail
+    /// use halo2curves_axiom::bn256::Fr;
+    /// use pse_poseidon::Spec;
+    ///
+    /// let spec = Spec::<Fr, 2, 1>::new(8, 56);
+    /// spec.encrypt(Fr::from(1), Fr::from(2), &[Fr::from(3)]);
+    /// ```
+    pub fn encrypt(&self, key: F, nonce: F, plaintext: &[F]) -> (Vec<F>, F) {
+        let mut state = self.encryption_state(key, nonce, plaintext.len());
+        let mut ciphertext = Vec::with_capacity(plaintext.len());
+        for chunk in plaintext.chunks(RATE) {
+            for (word, message) in state.0.iter_mut().skip(1).zip(chunk.iter()) {
+                *word += message;
+                ciphertext.push(*word);
+            }
+            self.permute(&mut state);
+        }
+        (ciphertext, state.result())
+    }
+
+    /// Decrypts `ciphertext` produced by `encrypt` with the same `key` and
+    /// `nonce`. Tag is compared in constant time and plaintext is returned
+    /// only if it matches. Security assumptions are the same as in `encrypt`
+    pub fn decrypt(&self, key: F, nonce: F, ciphertext: &[F], tag: F) -> Result<Vec<F>, Error> {
+        let mut state = self.encryption_state(key, nonce, ciphertext.len());
+        let mut plaintext = Vec::with_capacity(ciphertext.len());
+        for chunk in ciphertext.chunks(RATE) {
+            for (word, cipher) in state.0.iter_mut().skip(1).zip(chunk.iter()) {
+                plaintext.push(*cipher - *word);
+                *word = *cipher;
+            }
+            self.permute(&mut state);
+        }
+
+        if bool::from(state.result().ct_eq(&tag)) {
+            Ok(plaintext)
+        } else {
+            Err(Error::InvalidTag)
+        }
+    }
+
+    fn encryption_state(&self, key: F, nonce: F, length: usize) -> State<F, T> {
+        let () = EncryptionRate::<RATE>::CHECK;
+        let mut state = State::fixed_length(length);
+        state.0[0] += F::from(ENCRYPTION_TAG);
+        state.0[1] += key;
+        state.0[2] += nonce;
+        self.permute(&mut state);
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Error, Spec};
+    use halo2curves_axiom::bn256::Fr;
+    use halo2curves_axiom::group::ff::Field;
+    use rand_core::OsRng;
+
+    #[test]
+    fn test_encrypt_decrypt() {
+        let spec = Spec::<Fr, 5, 4>::new(8, 60);
+        let (key, nonce) = (Fr::random(OsRng), Fr::random(OsRng));
+
+        for len in [0, 1, 4, 7] {
+            let plaintext = (0..len).map(|_| Fr::random(OsRng)).collect::<Vec<Fr>>();
+            let (ciphertext, tag) = spec.encrypt(key, nonce, &plaintext);
+            assert_eq!(ciphertext.len(), len);
+            assert_eq!(spec.decrypt(key, nonce, &ciphertext, tag), Ok(plaintext));
+
+            let wrong_key = key + Fr::one();
+            assert_eq!(
+                spec.decrypt(wrong_key, nonce, &ciphertext, tag),
+                Err(Error::InvalidTag)
+            );
+            assert_eq!(
+                spec.decrypt(key, nonce, &ciphertext, tag + Fr::one()),
+                Err(Error::InvalidTag)
+            );
+        }
+
+        let plaintext = vec![Fr::from(1), Fr::from(2), Fr::from(3)];
+        let (ciphertext, tag) = spec.encrypt(key, nonce, &plaintext);
+        for i in 0..ciphertext.len() {
+            let mut tampered = ciphertext.clone();
+            tampered[i] += Fr::one();
+            assert_eq!(
+                spec.decrypt(key, nonce, &tampered, tag),
+                Err(Error::InvalidTag)
+            );
+        }
+        assert_eq!(
+            spec.decrypt(key, nonce, &ciphertext[..2], tag),
+            Err(Error::InvalidTag)
+        );
+    }
+
+    #[test]
+    fn test_encryption_domain() {
+        let spec = Spec::<Fr, 3, 2>::new(8, 57);
+        let (key, nonce) = (Fr::random(OsRng), Fr::random(OsRng));
+
+        // Keyed state of a two element plaintext isn't the fixed length hash
+        // of the key and the nonce
+        let state = spec.encryption_state(key, nonce, 2);
+        assert_ne!(state.result(), spec.hash_two(key, nonce));
+    }
+}
@@ -0,0 +1,128 @@
+use alloc::string::String;
+use core::fmt;
+
+/// Errors that can occur in fallible hashing operations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// Fixed length hasher is squeezed after absorbing a number of elements
+    /// other than its arity, or a message started with `begin_message` has
+    /// a number of elements other than the declared length
+    InvalidInputLength {
+        /// Arity of the hasher or the declared message length
+        expected: usize,
+        /// Number of absorbed elements
+        actual: usize,
+    },
+    /// Sbox exponent is not coprime to `p - 1` so the sbox is not a
+    /// permutation
+    InvalidAlpha {
+        /// Rejected exponent
+        alpha: u64,
+    },
+    /// Number of full rounds is not a positive even number
+    InvalidRounds {
+        /// Rejected number of full rounds
+        r_f: usize,
+    },
+    /// Number of provided constants doesn't match the parameters
+    InvalidConstantsShape {
+        /// Expected number of rows or elements in a row
+        expected: usize,
+        /// Provided number of rows or elements in a row
+        actual: usize,
+    },
+    /// String is not a hex encoding of a canonical field element
+    InvalidHex {
+        /// Rejected string
+        value: String,
+    },
+    /// Provided matrix is not MDS
+    InvalidMds,
+    /// State width is not one more than the rate
+    InvalidWidth {
+        /// Rejected state width
+        width: usize,
+        /// Rate of the sponge
+        rate: usize,
+    },
+    /// Index is not less than the number of elements
+    IndexOutOfRange {
+        /// Rejected index
+        index: usize,
+        /// Number of elements
+        len: usize,
+    },
+    /// Hashing would take more permutations than allowed
+    PermutationBudgetExceeded {
+        /// Number of permutations hashing would take
+        required: usize,
+        /// Maximum number of permutations
+        budget: usize,
+    },
+    /// Authentication tag doesn't match the decrypted ciphertext
+    InvalidTag,
+    /// Context is bound after the hasher has absorbed or squeezed data
+    ContextNotFirst {
+        /// Number of elements absorbed before binding
+        absorbed: usize,
+    },
+    /// Value couldn't be serialized
+    Serialization {
+        /// Error reported by the serializer
+        message: String,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidInputLength { expected, actual } => write!(
+                f,
+                "expected {} input elements but {} are absorbed",
+                expected, actual
+            ),
+            Error::InvalidAlpha { alpha } => {
+                write!(f, "sbox exponent {} is not coprime to p - 1", alpha)
+            }
+            Error::InvalidRounds { r_f } => {
+                write!(f, "number of full rounds {} is not positive and even", r_f)
+            }
+            Error::InvalidConstantsShape { expected, actual } => {
+                write!(
+                    f,
+                    "expected {} constants but {} are given",
+                    expected, actual
+                )
+            }
+            Error::InvalidHex { value } => {
+                write!(f, "{} is not a hex encoded field element", value)
+            }
+            Error::InvalidMds => write!(f, "matrix is not MDS"),
+            Error::InvalidWidth { width, rate } => {
+                write!(
+                    f,
+                    "state width {} is not one more than rate {}",
+                    width, rate
+                )
+            }
+            Error::IndexOutOfRange { index, len } => {
+                write!(f, "index {} is out of range for length {}", index, len)
+            }
+            Error::PermutationBudgetExceeded { required, budget } => write!(
+                f,
+                "hashing takes {} permutations but at most {} are allowed",
+                required, budget
+            ),
+            Error::InvalidTag => write!(f, "authentication tag doesn't match"),
+            Error::ContextNotFirst { absorbed } => write!(
+                f,
+                "context must be bound first but {} elements are absorbed",
+                absorbed
+            ),
+            Error::Serialization { message } => write!(f, "serialization failed: {}", message),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
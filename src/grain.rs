@@ -1,6 +1,8 @@
 use crate::spec::MDSMatrix;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
 use halo2curves_axiom::group::ff::{FromUniformBytes, PrimeField};
-use std::marker::PhantomData;
 
 /// Grain initializes round constants and MDS matrix at given sponge parameters
 pub(super) struct Grain<F: PrimeField, const T: usize, const RATE: usize> {
@@ -8,8 +10,16 @@ pub(super) struct Grain<F: PrimeField, const T: usize, const RATE: usize> {
     _field: PhantomData<F>,
 }
 
-impl<F: FromUniformBytes<64>, const T: usize, const RATE: usize> Grain<F, T, RATE> {
-    pub(crate) fn generate(r_f: usize, r_p: usize) -> (Vec<[F; T]>, MDSMatrix<F, T, RATE>) {
+impl<F: PrimeField, const T: usize, const RATE: usize> Grain<F, T, RATE> {
+    /// Generates round constants and the MDS matrix. Elements of the matrix
+    /// are sampled with `FromUniformBytes<N>`
+    pub(crate) fn generate<const N: usize>(
+        r_f: usize,
+        r_p: usize,
+    ) -> (Vec<[F; T]>, MDSMatrix<F, T, RATE>)
+    where
+        F: FromUniformBytes<N>,
+    {
         debug_assert!(T > 1 && T == RATE + 1);
 
         // Support only prime field construction
@@ -18,8 +28,9 @@ impl<F: FromUniformBytes<64>, const T: usize, const RATE: usize> Grain<F, T, RAT
         const SBOX_TYPE: u8 = 0;
 
         let field_size = F::NUM_BITS;
+        // Representation only needs to hold `NUM_BITS` bits, it may be padded
         let n_bytes = F::Repr::default().as_ref().len();
-        assert_eq!((field_size as f32 / 8.0).ceil() as usize, n_bytes);
+        assert!(8 * n_bytes >= field_size as usize);
         assert_eq!(r_f % 2, 0);
 
         // Pseudo random number generation. See:
@@ -59,10 +70,10 @@ impl<F: FromUniformBytes<64>, const T: usize, const RATE: usize> Grain<F, T, RAT
 
         let (mut xs, mut ys) = ([F::ZERO; T], [F::ZERO; T]);
         for x in xs.iter_mut() {
-            *x = grain.next_field_element_without_rejection();
+            *x = grain.next_field_element_without_rejection::<N>();
         }
         for y in ys.iter_mut() {
-            *y = grain.next_field_element_without_rejection();
+            *y = grain.next_field_element_without_rejection::<N>();
         }
 
         (constants, MDSMatrix::cauchy(&xs, &ys))
@@ -100,8 +111,12 @@ impl<F: FromUniformBytes<64>, const T: usize, const RATE: usize> Grain<F, T, RAT
     /// Credit: https://github.com/zcash/halo2/tree/main/halo2_gadgets/src/primitives/poseidon
     /// Returns the next field element from this Grain instantiation, without
     /// using rejection sampling.
-    pub(super) fn next_field_element_without_rejection(&mut self) -> F {
-        let mut bytes = [0u8; 64];
+    pub(super) fn next_field_element_without_rejection<const N: usize>(&mut self) -> F
+    where
+        F: FromUniformBytes<N>,
+    {
+        assert!(8 * N >= F::NUM_BITS as usize);
+        let mut bytes = [0u8; N];
 
         // Poseidon reference impl interprets the bits as a repr in MSB order, because
         // it's easy to do that in Python. Additionally, it does not use rejection
@@ -112,8 +127,8 @@ impl<F: FromUniformBytes<64>, const T: usize, const RATE: usize> Grain<F, T, RAT
         // systems due to not rejecting canonical forms).
         //
         // Given that we don't want to diverge from the reference implementation, we
-        // hack around this restriction by serializing the bits into a 64-byte
-        // array and then calling F::from_bytes_wide. PLEASE DO NOT COPY THIS
+        // hack around this restriction by serializing the bits into an `N` byte
+        // array and then calling F::from_uniform_bytes. PLEASE DO NOT COPY THIS
         // INTO YOUR OWN CODE!
         let view = bytes.as_mut();
         for (i, bit) in self.take(F::NUM_BITS as usize).enumerate() {
@@ -142,7 +157,7 @@ impl<F: FromUniformBytes<64>, const T: usize, const RATE: usize> Grain<F, T, RAT
     }
 }
 
-impl<F: FromUniformBytes<64>, const T: usize, const RATE: usize> Iterator for Grain<F, T, RATE> {
+impl<F: PrimeField, const T: usize, const RATE: usize> Iterator for Grain<F, T, RATE> {
     type Item = bool;
 
     fn next(&mut self) -> Option<Self::Item> {
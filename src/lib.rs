@@ -1,14 +1,42 @@
 //! Poseidon hashing implementation with variable length input setting. This
-//! crate also exposes constant parameters for circuit implementations
+//! crate also exposes constant parameters for circuit implementations. With
+//! default features disabled the crate is `no_std` and only requires `alloc`
 
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
 #![deny(missing_debug_implementations)]
 #![deny(missing_docs)]
 
+extern crate alloc;
+
+mod accumulator;
+mod bytes;
+mod circom;
+mod commitment;
+mod config;
+mod encryption;
+mod error;
 mod grain;
 mod matrix;
+pub mod merkle;
+pub mod params;
 mod permutation;
 mod poseidon;
+#[cfg(feature = "std")]
+mod registry;
+#[cfg(feature = "serde")]
+mod serialization;
 mod spec;
+mod two_to_one;
+mod wide;
 
-pub use crate::poseidon::Poseidon;
-pub use crate::spec::{MDSMatrices, MDSMatrix, SparseMDSMatrix, Spec, State};
+pub use crate::accumulator::{LinearAccumulator, MultisetAccumulator};
+pub use crate::bytes::{from_limbs, to_limbs, ReprBytes, ReprSize};
+pub use crate::commitment::{VectorCommitment, VectorOpening};
+pub use crate::config::PoseidonConfig;
+pub use crate::error::Error;
+pub use crate::poseidon::{Poseidon, ResultMode};
+#[cfg(feature = "std")]
+pub use crate::registry::SpecRegistry;
+pub use crate::spec::{MDSMatrices, MDSMatrix, OptimizedConstants, SparseMDSMatrix, Spec, State};
+pub use crate::two_to_one::poseidon2to1;
+pub use crate::wide::WidePoseidon;
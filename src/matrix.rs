@@ -3,8 +3,13 @@
 //! with the intention of construction of parameters and are not used in the
 //! actual permutation process.
 
+use alloc::vec::Vec;
 use halo2curves_axiom::group::ff::PrimeField;
 
+/// Largest width of a non Cauchy matrix that `Matrix::is_mds` checks
+/// exhaustively
+const MAX_EXHAUSTIVE_MDS_WIDTH: usize = 8;
+
 #[derive(PartialEq, Debug, Clone)]
 pub(crate) struct Matrix<F: PrimeField, const T: usize>(pub(crate) [[F; T]; T]);
 
@@ -125,6 +130,57 @@ impl<F: PrimeField, const T: usize> Matrix<F, T> {
         res
     }
 
+    /// Returns true if all square submatrices are non singular. Cauchy
+    /// matrices are recognized in `O(T^2)`, other matrices are checked
+    /// exhaustively which is only feasible up to `MAX_EXHAUSTIVE_MDS_WIDTH`
+    /// so that wider non Cauchy matrices are rejected
+    pub(crate) fn is_mds(&self) -> bool {
+        self.is_cauchy() || (T <= MAX_EXHAUSTIVE_MDS_WIDTH && self.all_minors_non_singular())
+    }
+
+    /// Returns true if entries are `1 / (x_i + y_j)` with distinct `x_i` and
+    /// distinct `y_j`. Every square submatrix of such matrix is again a
+    /// Cauchy matrix and hence non singular
+    fn is_cauchy(&self) -> bool {
+        // inverted entries are `x_i + y_j` which holds exactly when
+        // `a_ij + a_00 = a_i0 + a_0j` taking `x_i = a_i0, y_j = a_0j - a_00`
+        let mut a = [[F::ZERO; T]; T];
+        for (a_row, row) in a.iter_mut().zip(self.0.iter()) {
+            for (a_ij, e) in a_row.iter_mut().zip(row.iter()) {
+                match Option::<F>::from(e.invert()) {
+                    Some(inverse) => *a_ij = inverse,
+                    None => return false,
+                }
+            }
+        }
+        let distinct = |e: &[F]| (0..e.len()).all(|i| !e[i + 1..].contains(&e[i]));
+        let xs: Vec<F> = a.iter().map(|row| row[0]).collect();
+        distinct(&xs)
+            && distinct(&a[0])
+            && a.iter().all(|row| {
+                row.iter()
+                    .zip(a[0].iter())
+                    .all(|(a_ij, a_0j)| *a_ij + a[0][0] == row[0] + a_0j)
+            })
+    }
+
+    /// Returns true if all of the `C(2T, T) - 1` square submatrices are non
+    /// singular
+    fn all_minors_non_singular(&self) -> bool {
+        let subsets = |size: u32| (0u64..1 << T).filter(move |mask| mask.count_ones() == size);
+        (1..=T as u32).all(|size| {
+            subsets(size).all(|rows| {
+                subsets(size).all(|cols| {
+                    let select = |mask: u64| (0..T).filter(move |i| mask & (1 << i) != 0);
+                    let sub = select(rows)
+                        .map(|i| select(cols).map(|j| self.0[i][j]).collect())
+                        .collect();
+                    !bool::from(determinant(sub).is_zero())
+                })
+            })
+        })
+    }
+
     #[inline]
     pub(crate) fn w<const RATE: usize>(&self) -> [F; RATE] {
         assert_eq!(RATE + 1, T);
@@ -143,3 +199,61 @@ impl<F: PrimeField, const T: usize> Matrix<F, T> {
         Matrix::<F, RATE>::from_vec(self.0.iter().skip(1).map(|row| row[1..].to_vec()).collect())
     }
 }
+
+/// Determinant with Gaussian elimination
+fn determinant<F: PrimeField>(mut m: Vec<Vec<F>>) -> F {
+    let n = m.len();
+    let mut det = F::ONE;
+    for i in 0..n {
+        let pivot = match (i..n).find(|&row| !bool::from(m[row][i].is_zero())) {
+            Some(pivot) => pivot,
+            None => return F::ZERO,
+        };
+        if pivot != i {
+            m.swap(pivot, i);
+            det = -det;
+        }
+        det *= m[i][i];
+        let inverse = m[i][i].invert().unwrap();
+        let (upper, lower) = m.split_at_mut(i + 1);
+        let pivot_row = &upper[i];
+        for row in lower.iter_mut() {
+            let factor = row[i] * inverse;
+            for (e, pivot_e) in row.iter_mut().zip(pivot_row.iter()).skip(i) {
+                *e -= factor * pivot_e;
+            }
+        }
+    }
+    det
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Matrix;
+    use crate::grain::Grain;
+    use halo2curves_axiom::bn256::Fr;
+    use halo2curves_axiom::group::ff::Field;
+
+    #[test]
+    fn test_is_mds() {
+        // Generated matrices are Cauchy and wide ones are not checked
+        // exhaustively
+        let (_, mds) = Grain::<Fr, 17, 16>::generate::<64>(8, 68);
+        assert!(mds.0.is_cauchy());
+        assert!(mds.0.is_mds());
+
+        let (_, mds) = Grain::<Fr, 5, 4>::generate::<64>(8, 60);
+        assert!(mds.0.all_minors_non_singular());
+        let mut repeated = mds.0;
+        repeated.0[1] = repeated.0[0];
+        assert!(!repeated.is_cauchy());
+        assert!(!repeated.is_mds());
+
+        // Not Cauchy but MDS
+        let vandermonde =
+            |xs: [u64; 3]| Matrix(xs.map(|x| [Fr::ONE, Fr::from(x), Fr::from(x * x)]));
+        assert!(!vandermonde([1, 2, 3]).is_cauchy());
+        assert!(vandermonde([1, 2, 3]).is_mds());
+        assert!(!vandermonde([1, 2, 2]).is_mds());
+    }
+}
@@ -0,0 +1,664 @@
+//! Merkle tree primitives built on the fixed length compression. Binary
+//! primitives use the two to one compression `Spec::hash_two` and
+//! `MerkleTree` compresses `RATE` children at once
+
+use crate::{Error, Spec, State};
+use alloc::vec;
+use alloc::vec::Vec;
+use core::hash::{Hash, Hasher};
+use halo2curves_axiom::group::ff::PrimeField;
+
+/// Returns roots of empty subtrees at each level from leaves to the root of a
+/// tree with given depth. First root is `empty_leaf` and each next one is the
+/// compression of the previous root with itself, so there are `depth + 1`
+/// roots
+pub fn empty_roots<F: PrimeField, const T: usize, const RATE: usize>(
+    depth: usize,
+    empty_leaf: F,
+    spec: &Spec<F, T, RATE>,
+) -> Vec<F> {
+    core::iter::successors(Some(empty_leaf), |root| Some(spec.hash_two(*root, *root)))
+        .take(depth + 1)
+        .collect()
+}
+
+/// Replaces a level of a binary tree with its parents computed with
+/// `hash_two`, writing each parent into the front of the same buffer and
+/// truncating it, so no new allocation is made. Last node of a level with odd
+/// number of nodes is paired with zero as in `MerkleTree`
+pub fn merkle_level_in_place<F: PrimeField, const T: usize, const RATE: usize>(
+    nodes: &mut Vec<F>,
+    spec: &Spec<F, T, RATE>,
+) {
+    let parents = nodes.len().div_ceil(2);
+    for i in 0..parents {
+        // Parent `i` only overwrites nodes that are already compressed
+        let right = nodes.get(2 * i + 1).copied().unwrap_or(F::ZERO);
+        nodes[i] = spec.hash_two(nodes[2 * i], right);
+    }
+    nodes.truncate(parents);
+}
+
+/// Merkle inclusion proof of a leaf in `MerkleTree`. For each level from the
+/// leaves up to the root it has the position of the node among its siblings
+/// and the other `RATE - 1` children of its parent in order. It is checked
+/// with `verify`, which also binds the number of leaves
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleProof<F: PrimeField> {
+    levels: Vec<(usize, Vec<F>)>,
+}
+
+/// Merkle path of a leaf, the same as `MerkleProof`
+pub type MerklePath<F> = MerkleProof<F>;
+
+impl<F: PrimeField> MerkleProof<F> {
+    /// Constructs a proof from positions and siblings ordered from the leaf
+    /// level up to the root
+    pub fn new(levels: Vec<(usize, Vec<F>)>) -> Self {
+        Self { levels }
+    }
+
+    /// Position among siblings and the siblings of each level from the leaves
+    /// up to the root
+    pub fn levels(&self) -> &[(usize, Vec<F>)] {
+        &self.levels
+    }
+}
+
+impl<F: PrimeField> Hash for MerkleProof<F> {
+    /// Hashes canonical representations of siblings so that it is
+    /// consistent with `PartialEq`
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.levels.len().hash(state);
+        for (position, siblings) in self.levels.iter() {
+            position.hash(state);
+            siblings.len().hash(state);
+            for sibling in siblings.iter() {
+                sibling.to_repr().as_ref().hash(state);
+            }
+        }
+    }
+}
+
+/// Fixed length compression of `RATE` children into their parent. It is the
+/// fixed length hashing of `RATE` elements as in
+/// `Poseidon::new_fixed_length(RATE, ..)` and takes a single permutation
+fn compress<F: PrimeField, const T: usize, const RATE: usize>(
+    children: &[F],
+    spec: &Spec<F, T, RATE>,
+) -> F {
+    debug_assert_eq!(children.len(), RATE);
+    let mut state = State::<F, T>::fixed_length(RATE);
+    for (word, child) in state.0.iter_mut().skip(1).zip(children.iter()) {
+        *word += child;
+    }
+    spec.permute(&mut state);
+    state.result()
+}
+
+/// Merkle tree with arity `RATE` where each node is the fixed length
+/// compression of its children, so it is binary for `T = 3, RATE = 2`. Each
+/// level with a number of nodes that is not a multiple of the arity is padded
+/// with zeros before computing its parents. Padding is not distinguished from
+/// zero leaves, so for example `[a, b, c]` and `[a, b, c, 0]` have the same
+/// root, and leaves are compressed the same way as internal nodes. Root alone
+/// doesn't commit to the number of leaves, which the verifier must know, see
+/// `verify`
+#[derive(Debug, Clone)]
+pub struct MerkleTree<F: PrimeField, const T: usize, const RATE: usize> {
+    levels: Vec<Vec<F>>,
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize> MerkleTree<F, T, RATE> {
+    /// Builds the tree over `leaves`. Panics if there are no leaves
+    pub fn new(leaves: Vec<F>, spec: &Spec<F, T, RATE>) -> Self {
+        assert!(!leaves.is_empty(), "merkle tree needs at least one leaf");
+        assert!(RATE >= 2, "merkle tree arity must be at least two");
+        let mut levels = vec![leaves];
+        while levels.last().unwrap().len() > 1 {
+            let parents = padded_chunks::<F, RATE>(levels.last().unwrap())
+                .map(|children| compress(&children, spec))
+                .collect();
+            levels.push(parents);
+        }
+        Self { levels }
+    }
+
+    /// Returns the root of the tree
+    pub fn root(&self) -> F {
+        self.levels.last().unwrap()[0]
+    }
+
+    /// Returns the path of the leaf at `leaf_index`. Fails if the index is out
+    /// of range
+    pub fn prove(&self, leaf_index: usize) -> Result<MerkleProof<F>, Error> {
+        let leaves = self.levels[0].len();
+        if leaf_index >= leaves {
+            return Err(Error::IndexOutOfRange {
+                index: leaf_index,
+                len: leaves,
+            });
+        }
+
+        let mut index = leaf_index;
+        let levels = self
+            .levels
+            .iter()
+            .take(self.levels.len() - 1)
+            .map(|level| {
+                let position = index % RATE;
+                let start = index - position;
+                let siblings = (start..start + RATE)
+                    .filter(|i| *i != index)
+                    .map(|i| level.get(i).copied().unwrap_or(F::ZERO))
+                    .collect();
+                index /= RATE;
+                (position, siblings)
+            })
+            .collect();
+        Ok(MerkleProof { levels })
+    }
+
+    /// Returns the proofs of all leaves in leaf order, the same as `prove`
+    /// for each index. Siblings of each node are collected once per level
+    /// and shared by the proofs of all leaves below that node
+    pub fn all_proofs(&self) -> Vec<MerkleProof<F>> {
+        let depth = self.levels.len() - 1;
+        let mut paths = vec![Vec::with_capacity(depth); self.levels[0].len()];
+        let mut stride = 1;
+        for level in self.levels.iter().take(depth) {
+            let siblings = padded_chunks::<F, RATE>(level)
+                .flat_map(|group| {
+                    (0..RATE).map(move |position| {
+                        let mut siblings = group.clone();
+                        siblings.remove(position);
+                        siblings
+                    })
+                })
+                .collect::<Vec<_>>();
+            for (leaf_index, path) in paths.iter_mut().enumerate() {
+                let index = leaf_index / stride;
+                path.push((index % RATE, siblings[index].clone()));
+            }
+            stride *= RATE;
+        }
+        paths
+            .into_iter()
+            .map(|levels| MerkleProof { levels })
+            .collect()
+    }
+}
+
+/// Returns true if `leaf` is included under `root` of a `MerkleTree` built
+/// over `leaves` leaves with `spec`. Path must have one level per level of
+/// the tree and point to an index below `leaves`, otherwise an internal node
+/// with a truncated path or a padding zero would be accepted as a leaf
+pub fn verify<F: PrimeField, const T: usize, const RATE: usize>(
+    root: F,
+    leaf: F,
+    path: &MerkleProof<F>,
+    leaves: usize,
+    spec: &Spec<F, T, RATE>,
+) -> bool {
+    if leaves == 0 || path.levels.len() != tree_depth::<RATE>(leaves) {
+        return false;
+    }
+    let mut node = leaf;
+    let mut index = 0;
+    let mut stride = 1;
+    for (position, siblings) in path.levels.iter() {
+        if *position >= RATE || siblings.len() != RATE - 1 {
+            return false;
+        }
+        index += position * stride;
+        stride *= RATE;
+        let mut children = siblings.clone();
+        children.insert(*position, node);
+        node = compress(&children, spec);
+    }
+    index < leaves && node == root
+}
+
+/// Commits to the evaluations of a polynomial as the root of a `MerkleTree`
+/// over them in order. It is a hash based commitment, not an algebraic
+/// polynomial commitment scheme such as KZG, so it has no homomorphic
+/// properties, openings are Merkle paths of logarithmic size and the degree
+/// is not enforced. Number of evaluations is not bound either, so it should
+/// be fixed by the protocol. Panics if there are no evaluations
+pub fn commit_poly_evals<F: PrimeField, const T: usize, const RATE: usize>(
+    evals: &[F],
+    spec: &Spec<F, T, RATE>,
+) -> F {
+    MerkleTree::new(evals.to_vec(), spec).root()
+}
+
+/// Returns true if `value` is the evaluation at `index` committed in
+/// `commitment` by `commit_poly_evals` over `len` evaluations. Path is the
+/// one given by `MerkleTree::prove` for the same index, and unlike `verify`
+/// its positions must also match the index
+pub fn verify_eval_opening<F: PrimeField, const T: usize, const RATE: usize>(
+    commitment: F,
+    len: usize,
+    index: usize,
+    value: F,
+    path: &MerkleProof<F>,
+    spec: &Spec<F, T, RATE>,
+) -> bool {
+    let mut remaining = index;
+    for (position, _) in path.levels.iter() {
+        if *position != remaining % RATE {
+            return false;
+        }
+        remaining /= RATE;
+    }
+    remaining == 0 && verify(commitment, value, path, len, spec)
+}
+
+/// Append only Merkle tree that maintains the same root as `MerkleTree` over
+/// the leaves appended so far. It is the incremental tree technique where
+/// only the complete subtrees that are not yet grouped with their siblings
+/// are kept, at most `RATE - 1` per level. Appending takes amortized
+/// `1 / (RATE - 1)` compressions and computing the root compresses the
+/// partial group of each level once, so both are `O(log n)` at worst
+#[derive(Debug, Clone)]
+pub struct StreamingMerkle<F: PrimeField, const T: usize, const RATE: usize> {
+    spec: Spec<F, T, RATE>,
+    frontier: Vec<Vec<F>>,
+    len: usize,
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize> StreamingMerkle<F, T, RATE> {
+    /// Constructs an empty tree compressing nodes with `spec`
+    pub fn new(spec: Spec<F, T, RATE>) -> Self {
+        assert!(RATE >= 2, "merkle tree arity must be at least two");
+        Self {
+            spec,
+            frontier: vec![],
+            len: 0,
+        }
+    }
+
+    /// Appends a leaf and compresses every subtree that gets complete
+    pub fn append(&mut self, leaf: F) {
+        let mut node = leaf;
+        let mut level = 0;
+        loop {
+            if self.frontier.len() == level {
+                self.frontier.push(Vec::with_capacity(RATE));
+            }
+            let group = &mut self.frontier[level];
+            group.push(node);
+            if group.len() < RATE {
+                break;
+            }
+            node = compress(group, &self.spec);
+            group.clear();
+            level += 1;
+        }
+        self.len += 1;
+    }
+
+    /// Returns the root of the tree over the leaves appended so far, which is
+    /// equal to `MerkleTree::new(leaves, spec).root()`. Root of the empty tree
+    /// is zero
+    pub fn root(&self) -> F {
+        let depth = tree_depth::<RATE>(self.len);
+
+        // Partial group of each level is padded with zeros and compressed
+        // together with the partial node carried from the level below
+        let mut carry = None;
+        for group in self.frontier.iter().take(depth) {
+            let mut children = group.clone();
+            children.extend(carry);
+            carry = (!children.is_empty()).then(|| {
+                children.resize(RATE, F::ZERO);
+                compress(&children, &self.spec)
+            });
+        }
+        carry
+            .or_else(|| {
+                self.frontier
+                    .get(depth)
+                    .and_then(|group| group.first().copied())
+            })
+            .unwrap_or(F::ZERO)
+    }
+
+    /// Number of appended leaves
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if no leaf is appended
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+/// Number of levels above the leaves of a `MerkleTree` over `leaves` leaves,
+/// that is zero for a single leaf
+fn tree_depth<const RATE: usize>(leaves: usize) -> usize {
+    let mut depth = 0;
+    let mut capacity = 1;
+    while capacity < leaves {
+        capacity *= RATE;
+        depth += 1;
+    }
+    depth
+}
+
+/// Splits a level into groups of `RATE` children padding the last one with
+/// zeros
+fn padded_chunks<F: PrimeField, const RATE: usize>(
+    level: &[F],
+) -> impl Iterator<Item = Vec<F>> + '_ {
+    level.chunks(RATE).map(|chunk| {
+        let mut children = chunk.to_vec();
+        children.resize(RATE, F::ZERO);
+        children
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        commit_poly_evals, empty_roots, merkle_level_in_place, verify, verify_eval_opening,
+        MerkleProof, MerkleTree, StreamingMerkle,
+    };
+    use crate::Error;
+    use crate::Spec;
+    use halo2curves_axiom::bn256::Fr;
+    use halo2curves_axiom::group::ff::Field;
+    use rand_core::OsRng;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_empty_roots() {
+        let spec = Spec::<Fr, 3, 2>::new(8, 57);
+        let empty_leaf = Fr::from(0);
+
+        assert_eq!(empty_roots(0, empty_leaf, &spec), vec![empty_leaf]);
+
+        let roots = empty_roots(32, empty_leaf, &spec);
+        assert_eq!(roots.len(), 33);
+        assert_eq!(roots[0], empty_leaf);
+        for (child, parent) in roots.iter().zip(roots.iter().skip(1)) {
+            assert_eq!(*parent, spec.hash_two(*child, *child));
+        }
+    }
+
+    #[test]
+    fn test_merkle_level_in_place() {
+        let spec = Spec::<Fr, 3, 2>::new(8, 57);
+        for number_of_nodes in [0, 1, 2, 7, 8] {
+            let nodes = (0..number_of_nodes)
+                .map(|_| Fr::random(OsRng))
+                .collect::<Vec<Fr>>();
+            let expected = nodes
+                .chunks(2)
+                .map(|pair| spec.hash_two(pair[0], pair.get(1).copied().unwrap_or(Fr::zero())))
+                .collect::<Vec<Fr>>();
+
+            let mut level = nodes.clone();
+            let capacity = level.capacity();
+            merkle_level_in_place(&mut level, &spec);
+            assert_eq!(level, expected);
+            assert_eq!(level.capacity(), capacity);
+        }
+
+        // Repeated until the root gives the root of the binary tree
+        let leaves = (0..5).map(|_| Fr::random(OsRng)).collect::<Vec<Fr>>();
+        let mut nodes = leaves.clone();
+        while nodes.len() > 1 {
+            merkle_level_in_place(&mut nodes, &spec);
+        }
+        assert_eq!(nodes[0], MerkleTree::new(leaves, &spec).root());
+    }
+
+    #[test]
+    fn test_merkle_proof_eq_and_hash() {
+        let spec = Spec::<Fr, 3, 2>::new(8, 57);
+        let leaves = (0..5).map(|_| Fr::random(OsRng)).collect::<Vec<Fr>>();
+        let tree = MerkleTree::new(leaves.clone(), &spec);
+        let proof = tree.prove(1).unwrap();
+
+        let mut flipped = proof.levels().to_vec();
+        flipped[1].0 = 1 - flipped[1].0;
+        let mut tampered = proof.levels().to_vec();
+        tampered[2].1[0] += Fr::one();
+        let others = [
+            tree.prove(2).unwrap(),
+            MerkleProof::new(flipped),
+            MerkleProof::new(tampered),
+            MerkleProof::new(proof.levels()[..2].to_vec()),
+        ];
+
+        assert_eq!(proof, tree.prove(1).unwrap());
+        assert_eq!(proof, MerkleProof::new(proof.levels().to_vec()));
+        let mut set = HashSet::new();
+        assert!(set.insert(proof.clone()));
+        assert!(!set.insert(proof.clone()));
+        for other in others.iter() {
+            assert_ne!(proof, *other);
+            assert!(!verify(tree.root(), leaves[1], other, 5, &spec));
+            assert!(set.insert(other.clone()));
+        }
+    }
+
+    #[test]
+    fn test_merkle_tree() {
+        // Binary tree matches `hash_two`
+        let spec = Spec::<Fr, 3, 2>::new(8, 57);
+        let leaves = (0..3).map(|_| Fr::random(OsRng)).collect::<Vec<Fr>>();
+        let tree = MerkleTree::new(leaves.clone(), &spec);
+        let expected = spec.hash_two(
+            spec.hash_two(leaves[0], leaves[1]),
+            spec.hash_two(leaves[2], Fr::zero()),
+        );
+        assert_eq!(tree.root(), expected);
+        assert_eq!(MerkleTree::new(vec![leaves[0]], &spec).root(), leaves[0]);
+
+        let spec = Spec::<Fr, 5, 4>::new(8, 60);
+        for number_of_leaves in [1, 4, 6, 17] {
+            let leaves = (0..number_of_leaves)
+                .map(|_| Fr::random(OsRng))
+                .collect::<Vec<Fr>>();
+            let tree = MerkleTree::new(leaves.clone(), &spec);
+            let root = tree.root();
+
+            for (index, leaf) in leaves.iter().enumerate() {
+                let path = tree.prove(index).unwrap();
+                assert!(verify(root, *leaf, &path, number_of_leaves, &spec));
+                assert!(!verify(
+                    root,
+                    *leaf + Fr::one(),
+                    &path,
+                    number_of_leaves,
+                    &spec
+                ));
+
+                // Tampering with any sibling
+                for level in 0..path.levels().len() {
+                    for sibling in 0..3 {
+                        let mut tampered = path.clone();
+                        tampered.levels[level].1[sibling] += Fr::one();
+                        assert!(!verify(root, *leaf, &tampered, number_of_leaves, &spec));
+                    }
+                }
+            }
+            assert_eq!(
+                tree.prove(number_of_leaves),
+                Err(Error::IndexOutOfRange {
+                    index: number_of_leaves,
+                    len: number_of_leaves
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn test_merkle_tree_verify_binds_leaves() {
+        let spec = Spec::<Fr, 5, 4>::new(8, 60);
+        let leaves = (0..6).map(|_| Fr::random(OsRng)).collect::<Vec<Fr>>();
+        let tree = MerkleTree::new(leaves.clone(), &spec);
+        let root = tree.root();
+
+        // Internal node with a truncated path
+        let path = tree.prove(0).unwrap();
+        let truncated = MerkleProof {
+            levels: path.levels[1..].to_vec(),
+        };
+        assert!(!verify(root, tree.levels[1][0], &truncated, 6, &spec));
+        assert!(!verify(
+            root,
+            root,
+            &MerkleProof { levels: vec![] },
+            6,
+            &spec
+        ));
+
+        // Padding zero at an index past the leaves
+        let padding = MerkleProof {
+            levels: vec![
+                (2, vec![leaves[4], leaves[5], Fr::zero()]),
+                (1, vec![tree.levels[1][0], Fr::zero(), Fr::zero()]),
+            ],
+        };
+        assert!(!verify(root, Fr::zero(), &padding, 6, &spec));
+
+        // Explicit zero leaves give the same root as padding, so the number of
+        // leaves is what tells them apart
+        let mut padded = leaves.clone();
+        padded.push(Fr::zero());
+        let padded_tree = MerkleTree::new(padded, &spec);
+        assert_eq!(padded_tree.root(), root);
+        assert_eq!(padded_tree.prove(6).unwrap(), padding);
+        assert!(verify(root, Fr::zero(), &padding, 7, &spec));
+
+        // Path of a larger tree is rejected
+        let path = tree.prove(5).unwrap();
+        assert!(verify(root, leaves[5], &path, 6, &spec));
+        assert!(!verify(root, leaves[5], &path, 17, &spec));
+        assert!(!verify(root, leaves[5], &path, 4, &spec));
+        assert!(!verify(root, leaves[5], &path, 0, &spec));
+    }
+
+    #[test]
+    fn test_merkle_tree_all_proofs() {
+        let spec = Spec::<Fr, 5, 4>::new(8, 60);
+        for number_of_leaves in [1, 4, 6, 17] {
+            let leaves = (0..number_of_leaves)
+                .map(|_| Fr::random(OsRng))
+                .collect::<Vec<Fr>>();
+            let tree = MerkleTree::new(leaves.clone(), &spec);
+
+            let proofs = tree.all_proofs();
+            assert_eq!(proofs.len(), number_of_leaves);
+            for (index, (leaf, proof)) in leaves.iter().zip(proofs.iter()).enumerate() {
+                assert_eq!(*proof, tree.prove(index).unwrap());
+                assert!(verify(tree.root(), *leaf, proof, number_of_leaves, &spec));
+            }
+        }
+    }
+
+    #[test]
+    fn test_streaming_merkle() {
+        let binary = Spec::<Fr, 3, 2>::new(8, 57);
+        let mut tree = StreamingMerkle::new(binary.clone());
+        assert!(tree.is_empty());
+        assert_eq!(tree.root(), Fr::zero());
+
+        // Root after each append matches the full tree
+        let leaves = (0..17).map(|_| Fr::random(OsRng)).collect::<Vec<Fr>>();
+        for (i, leaf) in leaves.iter().enumerate() {
+            tree.append(*leaf);
+            assert_eq!(tree.len(), i + 1);
+            let expected = MerkleTree::new(leaves[..=i].to_vec(), &binary).root();
+            assert_eq!(tree.root(), expected);
+        }
+
+        let spec = Spec::<Fr, 5, 4>::new(8, 60);
+        let mut tree = StreamingMerkle::new(spec.clone());
+        let leaves = (0..70).map(|_| Fr::random(OsRng)).collect::<Vec<Fr>>();
+        for (i, leaf) in leaves.iter().enumerate() {
+            tree.append(*leaf);
+            if [0, 3, 4, 5, 15, 16, 17, 63, 64, 69].contains(&i) {
+                let expected = MerkleTree::new(leaves[..=i].to_vec(), &spec).root();
+                assert_eq!(tree.root(), expected);
+            }
+        }
+    }
+
+    #[test]
+    fn test_commit_poly_evals() {
+        let spec = Spec::<Fr, 3, 2>::new(8, 57);
+        let evals = (0..6).map(|_| Fr::random(OsRng)).collect::<Vec<Fr>>();
+        let len = evals.len();
+        let commitment = commit_poly_evals(&evals, &spec);
+        let tree = MerkleTree::new(evals.clone(), &spec);
+        assert_eq!(commitment, tree.root());
+
+        let verify_eval = |commitment: Fr, index: usize, value: Fr, path: &MerkleProof<Fr>| {
+            verify_eval_opening(commitment, len, index, value, path, &spec)
+        };
+        for (index, value) in evals.iter().enumerate() {
+            let path = tree.prove(index).unwrap();
+            assert!(verify_eval(commitment, index, *value, &path));
+            assert!(!verify_eval(commitment, index, *value + Fr::one(), &path));
+            assert!(!verify_eval(commitment + Fr::one(), index, *value, &path));
+        }
+
+        // Opening of a position is not accepted at another index
+        let path = tree.prove(2).unwrap();
+        for index in [3, 6, 10] {
+            assert!(!verify_eval(commitment, index, evals[2], &path));
+        }
+
+        // Equal evaluations at different positions
+        let mut evals = evals;
+        evals[4] = evals[1];
+        let tree = MerkleTree::new(evals.clone(), &spec);
+        let path = tree.prove(1).unwrap();
+        assert!(verify_eval(tree.root(), 1, evals[1], &path));
+        assert!(!verify_eval(tree.root(), 4, evals[1], &path));
+    }
+
+    #[test]
+    fn test_eval_opening_rejects_internal_nodes() {
+        let spec = Spec::<Fr, 3, 2>::new(8, 57);
+        let evals = (0..4).map(|_| Fr::random(OsRng)).collect::<Vec<Fr>>();
+        let commitment = commit_poly_evals(&evals, &spec);
+
+        // Commitment itself with an empty path
+        let empty = MerkleProof { levels: vec![] };
+        assert!(!verify(commitment, commitment, &empty, 4, &spec));
+        assert!(!verify_eval_opening(
+            commitment, 4, 0, commitment, &empty, &spec
+        ));
+
+        // Internal node with a path truncated to the levels above it
+        let node = spec.hash_two(evals[0], evals[1]);
+        let truncated = MerkleProof {
+            levels: vec![(0, vec![spec.hash_two(evals[2], evals[3])])],
+        };
+        assert!(!verify(commitment, node, &truncated, 4, &spec));
+        assert!(!verify_eval_opening(
+            commitment, 4, 0, node, &truncated, &spec
+        ));
+
+        // Index must be within the evaluations
+        let tree = MerkleTree::new(evals[..3].to_vec(), &spec);
+        let path = MerkleProof {
+            levels: vec![(1, vec![evals[2]]), (1, vec![tree.levels[1][0]])],
+        };
+        assert!(!verify(tree.root(), Fr::zero(), &path, 3, &spec));
+        assert!(!verify_eval_opening(
+            tree.root(),
+            3,
+            3,
+            Fr::zero(),
+            &path,
+            &spec
+        ));
+    }
+}
@@ -0,0 +1,84 @@
+//! Number of rounds for 128 bit security over 254 bit fields with `alpha = 5`
+//! and validation of parameter tables at compile time
+
+/// Entries are `(T, RATE, R_F, R_P)` for widths `3` to `17`, same as
+/// circomlib's Poseidon
+pub const SECURE_ROUNDS: [(usize, usize, usize, usize); 15] = [
+    (3, 2, 8, 57),
+    (4, 3, 8, 56),
+    (5, 4, 8, 60),
+    (6, 5, 8, 60),
+    (7, 6, 8, 63),
+    (8, 7, 8, 64),
+    (9, 8, 8, 63),
+    (10, 9, 8, 60),
+    (11, 10, 8, 66),
+    (12, 11, 8, 60),
+    (13, 12, 8, 65),
+    (14, 13, 8, 70),
+    (15, 14, 8, 60),
+    (16, 15, 8, 64),
+    (17, 16, 8, 68),
+];
+
+const _: () = assert!(is_valid_rounds_table(&SECURE_ROUNDS));
+
+/// Returns true if the number of full rounds is positive and even, number of
+/// partial rounds is positive and the rate leaves a single capacity element
+pub const fn is_valid_params(t: usize, rate: usize, r_f: usize, r_p: usize) -> bool {
+    r_f > 0 && r_f & 1 == 0 && r_p > 0 && rate + 1 == t
+}
+
+/// Returns true if every `(T, RATE, R_F, R_P)` entry satisfies
+/// `is_valid_params`. Evaluated in a constant, for example
+/// `const _: () = assert!(is_valid_rounds_table(&TABLE));`, an invalid entry
+/// fails the build
+pub const fn is_valid_rounds_table(table: &[(usize, usize, usize, usize)]) -> bool {
+    let mut i = 0;
+    while i < table.len() {
+        let (t, rate, r_f, r_p) = table[i];
+        if !is_valid_params(t, rate, r_f, r_p) {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Compile time check of parameters given as constant generics. Referring to
+/// `ValidParams::<T, RATE, R_F, R_P>::CHECK` fails the build if the parameters
+/// don't satisfy `is_valid_params`
+///
+/// ```compile_fail
+/// let () = pse_poseidon::params::ValidParams::<3, 2, 7, 57>::CHECK;
+/// ```
+#[derive(Debug)]
+pub struct ValidParams<const T: usize, const RATE: usize, const R_F: usize, const R_P: usize>;
+
+impl<const T: usize, const RATE: usize, const R_F: usize, const R_P: usize>
+    ValidParams<T, RATE, R_F, R_P>
+{
+    /// Evaluates to `()` for valid parameters and fails to compile otherwise
+    pub const CHECK: () = assert!(
+        is_valid_params(T, RATE, R_F, R_P),
+        "invalid poseidon parameters"
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{is_valid_rounds_table, ValidParams, SECURE_ROUNDS};
+
+    #[test]
+    fn test_rounds_table() {
+        assert!(is_valid_rounds_table(&SECURE_ROUNDS));
+        let () = ValidParams::<3, 2, 8, 57>::CHECK;
+
+        // Each kind of broken entry is caught
+        for broken in [(3, 2, 7, 57), (3, 2, 0, 57), (3, 2, 8, 0), (3, 3, 8, 57)] {
+            let mut table = SECURE_ROUNDS.to_vec();
+            table[1] = broken;
+            assert!(!is_valid_rounds_table(&table));
+        }
+    }
+}
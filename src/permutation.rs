@@ -1,6 +1,6 @@
 use halo2curves_axiom::group::ff::PrimeField;
 
-use crate::spec::{Spec, State};
+use crate::spec::{inverse_alpha, Spec, State};
 
 impl<F: PrimeField, const T: usize, const RATE: usize> Spec<F, T, RATE> {
     /// Applies the Poseidon permutation to the given state
@@ -11,11 +11,11 @@ impl<F: PrimeField, const T: usize, const RATE: usize> Spec<F, T, RATE> {
         {
             state.add_constants(&self.constants.start[0]);
             for round_constants in self.constants.start.iter().skip(1).take(r_f - 1) {
-                state.sbox_full();
+                state.sbox_full(self.alpha);
                 state.add_constants(round_constants);
                 self.mds_matrices.mds.apply(state);
             }
-            state.sbox_full();
+            state.sbox_full(self.alpha);
             state.add_constants(self.constants.start.last().unwrap());
             self.mds_matrices.pre_sparse_mds.apply(state)
         }
@@ -28,7 +28,7 @@ impl<F: PrimeField, const T: usize, const RATE: usize> Spec<F, T, RATE> {
                 .iter()
                 .zip(self.mds_matrices.sparse_matrices.iter())
             {
-                state.sbox_part();
+                state.sbox_part(self.alpha);
                 state.add_constant(round_constant);
                 sparse_mds.apply(state);
             }
@@ -37,20 +37,112 @@ impl<F: PrimeField, const T: usize, const RATE: usize> Spec<F, T, RATE> {
         // Second half of the full rounds
         {
             for round_constants in self.constants.end.iter() {
-                state.sbox_full();
+                state.sbox_full(self.alpha);
                 state.add_constants(round_constants);
                 self.mds_matrices.mds.apply(state);
             }
-            state.sbox_full();
+            state.sbox_full(self.alpha);
             self.mds_matrices.mds.apply(state);
         }
+
+        #[cfg(feature = "tracing")]
+        tracing::trace!(
+            rounds = self.r_f + self.constants.partial.len(),
+            fingerprint = state.trace_fingerprint(),
+            "permutation"
+        );
+    }
+
+    /// Applies only the first `n` rounds of the permutation, all of them if
+    /// `n` is at least `r_f + r_p`. Rounds are computed with the unoptimized
+    /// round constants and the MDS matrix, so the state is the one after `n`
+    /// rounds of the reference permutation and `n = 0` leaves it unchanged.
+    /// Intermediate state is not a Poseidon output and is insecure to use as
+    /// a hash. It is meant for benchmarking and analysis of round counts and
+    /// is slower than `permute`. Panics if the spec is constructed from
+    /// optimized constants with `from_constants` or deserialized, since the
+    /// unoptimized constants are unknown then
+    pub fn permute_rounds(&self, state: &mut State<F, T>, n: usize) {
+        assert!(
+            !self.round_constants.is_empty(),
+            "unoptimized round constants are not known"
+        );
+        let r_f = self.r_f / 2;
+        let partial_end = self.round_constants.len() - r_f;
+        for (round, round_constants) in self.round_constants.iter().enumerate().take(n) {
+            state.add_constants(round_constants);
+            if round < r_f || round >= partial_end {
+                state.sbox_full(self.alpha);
+            } else {
+                state.sbox_part(self.alpha);
+            }
+            self.mds_matrices.mds.apply(state);
+        }
+    }
+
+    /// Applies the inverse of the Poseidon permutation to the given state, so
+    /// that `permute_inverse` after `permute` gives back the original state.
+    /// It is much slower than `permute`, MDS matrix is inverted on each call,
+    /// each sparse matrix costs a field inversion and the inverse sbox is a
+    /// power with an exponent of the size of the field
+    pub fn permute_inverse(&self, state: &mut State<F, T>) {
+        let r_f = self.r_f / 2;
+        let d = inverse_alpha::<F>(self.alpha);
+        let mds = self.mds_matrices.mds.invert();
+        let sbox_inverse = |e: &mut F| *e = e.pow_vartime(&d);
+
+        // Second half of the full rounds
+        {
+            mds.apply(state);
+            state.0.iter_mut().for_each(sbox_inverse);
+            for round_constants in self.constants.end.iter().rev() {
+                mds.apply(state);
+                sub_constants(state, round_constants);
+                state.0.iter_mut().for_each(sbox_inverse);
+            }
+        }
+
+        // Partial rounds
+        {
+            for (round_constant, sparse_mds) in self
+                .constants
+                .partial
+                .iter()
+                .zip(self.mds_matrices.sparse_matrices.iter())
+                .rev()
+            {
+                sparse_mds.apply_inverse(state);
+                state.0[0] -= round_constant;
+                sbox_inverse(&mut state.0[0]);
+            }
+        }
+
+        // First half of the full rounds
+        {
+            self.mds_matrices.pre_sparse_mds.invert().apply(state);
+            sub_constants(state, self.constants.start.last().unwrap());
+            state.0.iter_mut().for_each(sbox_inverse);
+            for round_constants in self.constants.start.iter().skip(1).take(r_f - 1).rev() {
+                mds.apply(state);
+                sub_constants(state, round_constants);
+                state.0.iter_mut().for_each(sbox_inverse);
+            }
+            sub_constants(state, &self.constants.start[0]);
+        }
+    }
+}
+
+fn sub_constants<F: PrimeField, const T: usize>(state: &mut State<F, T>, constants: &[F; T]) {
+    for (word, constant) in state.0.iter_mut().zip(constants.iter()) {
+        *word -= constant;
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::State;
-    use crate::spec::{tests::SpecRef, Spec};
+    use crate::spec::{tests::SpecRef, Spec, DEFAULT_ALPHA};
+    use crate::Error;
     use halo2curves_axiom::bn256::Fr;
     use halo2curves_axiom::group::ff::PrimeField;
 
@@ -62,19 +154,19 @@ mod tests {
 
             for constants in self.constants.iter().take(r_f) {
                 state.add_constants(constants);
-                state.sbox_full();
+                state.sbox_full(DEFAULT_ALPHA);
                 self.mds.apply(state);
             }
 
             for constants in self.constants.iter().skip(r_f).take(r_p) {
                 state.add_constants(constants);
-                state.sbox_part();
+                state.sbox_part(DEFAULT_ALPHA);
                 self.mds.apply(state);
             }
 
             for constants in self.constants.iter().skip(r_f + r_p) {
                 state.add_constants(constants);
-                state.sbox_full();
+                state.sbox_full(DEFAULT_ALPHA);
                 self.mds.apply(state);
             }
         }
@@ -118,6 +210,7 @@ mod tests {
                 )*
             };
         }
+        run_test!([8, 57, 2, 1]);
         run_test!([8, 57, 3, 2]);
         run_test!([8, 57, 4, 3]);
         run_test!([8, 57, 5, 4]);
@@ -128,6 +221,141 @@ mod tests {
         run_test!([8, 57, 10, 9]);
     }
 
+    #[test]
+    fn test_permute_rounds() {
+        const R_F: usize = 8;
+        const R_P: usize = 57;
+
+        let spec = Spec::<Fr, 3, 2>::new(R_F, R_P);
+        let state = State([Fr::from(0), Fr::from(1), Fr::from(2)]);
+        let mut expected = state.clone();
+        spec.permute(&mut expected);
+
+        let mut full = state.clone();
+        spec.permute_rounds(&mut full, R_F + R_P);
+        assert_eq!(full, expected);
+
+        let mut partial = state.clone();
+        spec.permute_rounds(&mut partial, R_F + R_P - 1);
+        assert_ne!(partial, expected);
+
+        let mut saturated = state.clone();
+        spec.permute_rounds(&mut saturated, usize::MAX);
+        assert_eq!(saturated, expected);
+
+        // No rounds is the identity
+        let mut identity = state.clone();
+        spec.permute_rounds(&mut identity, 0);
+        assert_eq!(identity, state);
+
+        // Intermediate states match the reference permutation truncated to
+        // the first `n` rounds
+        for n in [1, R_F / 2, R_F / 2 + 1, R_F / 2 + R_P, R_F + R_P - 1] {
+            let mut rounds = state.clone();
+            spec.permute_rounds(&mut rounds, n);
+            let mut reference = SpecRef::<Fr, 3, 2>::new(R_F, R_P);
+            reference.constants.truncate(n);
+            let mut expected = state.clone();
+            reference.permute(&mut expected);
+            assert_eq!(rounds, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "unoptimized round constants are not known")]
+    fn test_permute_rounds_from_constants() {
+        let spec = Spec::<Fr, 3, 2>::new(8, 57);
+        let spec = Spec::from_constants(
+            spec.r_f(),
+            spec.alpha(),
+            spec.mds_matrices().clone(),
+            spec.constants().clone(),
+        )
+        .unwrap();
+        spec.permute_rounds(&mut State::default(), 1);
+    }
+
+    /// Sanity check of the output distribution, not a security property.
+    /// Hashes consecutive integers and runs a chi-square test over the values
+    /// of the output bytes. Two most significant bytes are skipped since the
+    /// output is below the modulus. Run with `cargo test -- --ignored`
+    #[test]
+    #[ignore]
+    fn test_output_byte_distribution() {
+        let spec = Spec::<Fr, 3, 2>::new(8, 57);
+        let mut buckets = [0u64; 256];
+        for i in 0..10000u64 {
+            let digest = spec.hash_two(Fr::from(i), Fr::from(0));
+            for byte in digest.to_repr()[..30].iter() {
+                buckets[*byte as usize] += 1;
+            }
+        }
+
+        let expected = buckets.iter().sum::<u64>() as f64 / 256.0;
+        let chi_square = buckets
+            .iter()
+            .map(|count| (*count as f64 - expected).powi(2) / expected)
+            .sum::<f64>();
+        // 255 degrees of freedom, the bound is about seven standard deviations
+        // above the mean
+        assert!(chi_square < 415.0, "chi square {} is too large", chi_square);
+    }
+
+    #[test]
+    fn test_permute_inverse() {
+        use halo2curves_axiom::group::ff::Field;
+        use rand_core::OsRng;
+
+        macro_rules! run_test {
+            ($T:expr, $RATE:expr, $R_P:expr, $ALPHA:expr) => {{
+                let spec = Spec::<Fr, $T, $RATE>::new_with_alpha(8, $R_P, $ALPHA).unwrap();
+                for _ in 0..4 {
+                    let state = State::<Fr, $T>(core::array::from_fn(|_| Fr::random(OsRng)));
+                    let mut permuted = state.clone();
+                    spec.permute(&mut permuted);
+                    assert_ne!(permuted, state);
+                    spec.permute_inverse(&mut permuted);
+                    assert_eq!(permuted, state);
+
+                    let mut inverted = state.clone();
+                    spec.permute_inverse(&mut inverted);
+                    spec.permute(&mut inverted);
+                    assert_eq!(inverted, state);
+                }
+            }};
+        }
+        run_test!(3, 2, 57, 5);
+        run_test!(5, 4, 60, 5);
+        run_test!(3, 2, 57, 7);
+    }
+
+    #[test]
+    fn test_alpha() {
+        const R_F: usize = 8;
+        const R_P: usize = 57;
+        const T: usize = 3;
+        const RATE: usize = 2;
+
+        let spec = Spec::<Fr, T, RATE>::new_with_alpha(R_F, R_P, 5).unwrap();
+        assert_eq!(spec, Spec::new(R_F, R_P));
+
+        let spec_7 = Spec::<Fr, T, RATE>::new_with_alpha(R_F, R_P, 7).unwrap();
+        assert_eq!(spec_7.alpha(), 7);
+        let state = State([Fr::from(0), Fr::from(1), Fr::from(2)]);
+        let (mut state_5, mut state_7) = (state.clone(), state);
+        spec.permute(&mut state_5);
+        spec_7.permute(&mut state_7);
+        assert_ne!(state_5, state_7);
+
+        // p - 1 is divisible by 2, 3 and 13
+        for alpha in [0, 1, 2, 3, 9, 13] {
+            assert_eq!(
+                Spec::<Fr, T, RATE>::new_with_alpha(R_F, R_P, alpha),
+                Err(Error::InvalidAlpha { alpha })
+            );
+        }
+    }
+
     #[test]
     fn test_against_test_vectors() {
         // https://extgit.iaik.tugraz.at/krypto/hadeshash/-/blob/master/code/test_vectors.txt
@@ -1,29 +1,457 @@
-use crate::{Spec, State};
+use crate::bytes::{
+    bytes_per_element, crc64, pack_bytes, pack_bytes_be, wide_bytes, write_be, ReprSize, CRC64_INIT,
+};
+use crate::spec::DEFAULT_ALPHA;
+use crate::{Error, Spec, State};
+use alloc::vec;
+use alloc::vec::Vec;
 use digest::{core_api::BlockSizeUser, FixedOutput, HashMarker, OutputSizeUser, Update};
 use halo2curves_axiom::group::ff::{FromUniformBytes, PrimeField};
+#[cfg(feature = "rng")]
+use rand_core::RngCore;
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+use typenum::U32;
+
+/// Tag absorbed ahead of external digests. It is the ASCII string `digest`
+/// read as a big endian integer
+const EXTERNAL_DIGEST_TAG: u64 = u64::from_be_bytes(*b"\0\0digest");
+
+/// Tag absorbed ahead of a bound context. It is the ASCII string `context`
+/// read as a big endian integer
+const CONTEXT_TAG: u64 = u64::from_be_bytes(*b"\0context");
+
+/// Tags absorbed ahead of public and private inputs. They are the ASCII
+/// strings `public` and `private` read as big endian integers
+const PUBLIC_TAG: u64 = u64::from_be_bytes(*b"\0\0public");
+const PRIVATE_TAG: u64 = u64::from_be_bytes(*b"\0private");
+
+/// Markers preceding absorbed and squeezed elements in the transcript log
+#[cfg(feature = "debug-transcript")]
+const TRANSCRIPT_ABSORB: u8 = 0;
+#[cfg(feature = "debug-transcript")]
+const TRANSCRIPT_SQUEEZE: u8 = 1;
+
+/// Maximum number of chunks of `RATE` elements `Poseidon::hash_n` accepts
+const HASH_N_MAX_CHUNKS: usize = 8;
+
+/// Compile time check of the arity of `Poseidon::hash_n`
+struct HashNArity<const N: usize, const RATE: usize>;
+
+impl<const N: usize, const RATE: usize> HashNArity<N, RATE> {
+    const CHECK: () = assert!(
+        N <= RATE * HASH_N_MAX_CHUNKS,
+        "hash_n arity is larger than RATE * HASH_N_MAX_CHUNKS"
+    );
+}
 
 /// Poseidon hasher that maintains state and inputs and yields single element
 /// output when desired
 #[derive(Debug, Clone)]
 pub struct Poseidon<F: PrimeField, const T: usize, const RATE: usize> {
-    state: State<F, T>,
-    spec: Spec<F, T, RATE>,
-    absorbing: Vec<F>,
+    pub(crate) state: State<F, T>,
+    pub(crate) spec: Spec<F, T, RATE>,
+    pub(crate) absorbing: Vec<F>,
+    pub(crate) absorbed: usize,
+    pub(crate) arity: Option<usize>,
+    /// Set when the hasher is squeezed and cleared when new elements are
+    /// absorbed or the hasher is reset. Following squeezes don't apply the
+    /// padding again and the `digest` path is guarded against finalizing an
+    /// already squeezed hasher
+    pub(crate) finalized: bool,
+    pub(crate) result_mode: ResultMode,
+    /// Bytes given to `digest::Update` that don't fill an element yet
+    pub(crate) pending_bytes: Vec<u8>,
+    /// Number of bytes given to `digest::Update`, `None` if it wasn't used
+    pub(crate) absorbed_bytes: Option<u64>,
+    /// Capacity element set with `new_with_domain` that `reset` restores
+    pub(crate) domain: Option<F>,
+    /// CRC register over absorbed elements if enabled with `with_checksum`
+    pub(crate) checksum: Option<u64>,
+    /// Number of elements declared with `begin_message` that must follow the
+    /// header before squeezing
+    pub(crate) message_length: Option<usize>,
+    /// Number of rate elements read per permutation in the squeeze phase set
+    /// with `with_squeeze_rate`, `RATE` by default
+    pub(crate) squeeze_rate: usize,
+    /// Log of absorbed and squeezed elements, see `transcript_bytes`
+    #[cfg(feature = "debug-transcript")]
+    pub(crate) transcript: Vec<u8>,
+}
+
+/// `ResultMode` selects how the output is extracted from the state after the
+/// final permutation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResultMode {
+    /// Output is the first rate element of the state
+    #[default]
+    Single,
+    /// Output is the field sum of all rate elements of the state. It is not
+    /// the standard extraction so digests are not compatible with other
+    /// implementations. Summing doesn't make the output stronger than reading
+    /// a single element, and since it is linear anyone who can fix all but
+    /// one rate element can fully control the output just as in the single
+    /// mode. Output of the sum and the single mode are equal for `RATE = 1`
+    Sum,
 }
 
 impl<F: FromUniformBytes<64>, const T: usize, const RATE: usize> Poseidon<F, T, RATE> {
     /// Constructs a clear state poseidon instance
     pub fn new(r_f: usize, r_p: usize) -> Self {
+        Self::from_spec(Spec::new(r_f, r_p))
+    }
+
+    /// Constructs a clear state poseidon instance with the capacity element
+    /// set to `domain` instead of the default `2**64`. Domain is restored by
+    /// `reset`, absorbing and padding are the same as in `new`
+    pub fn new_with_domain(r_f: usize, r_p: usize, domain: F) -> Self {
+        Self {
+            state: State::with_capacity(domain),
+            domain: Some(domain),
+            ..Self::new(r_f, r_p)
+        }
+    }
+
+    /// Constructs a clear state poseidon instance for fixed length hashing of
+    /// exactly `arity` elements. Capacity element is initialized to
+    /// `2**64 * arity + 2**63`, so it never equals the variable length
+    /// capacity `2**64`, and squeezing doesn't apply the variable length
+    /// padding, last chunk is only padded with zeros if it is not full
+    pub fn new_fixed_length(arity: usize, r_f: usize, r_p: usize) -> Self {
+        Self {
+            state: State::fixed_length(arity),
+            arity: Some(arity),
+            ..Self::new(r_f, r_p)
+        }
+    }
+
+    /// Constant input length hash of `N` elements as computed by circuit
+    /// gadgets, for example halo2 `ConstantLength`. Capacity element is
+    /// `2**64 * N` as in the gadgets, inputs are absorbed in chunks of `RATE`
+    /// and only the last chunk is padded with zeros, so it takes
+    /// `ceil(N / RATE)` permutations, or a single one for `N = 0`. There is no
+    /// finishing `F::ONE` element, so results are not interoperable with the
+    /// variable length `update` and `squeeze`. Capacity differs from the one
+    /// of `hash_tagged` by the `2**63` offset, so for `N = 1` the initial
+    /// state is the one of the variable length hashing as in the gadgets
+    pub fn hash<const N: usize>(inputs: [F; N], r_f: usize, r_p: usize) -> F {
+        let mut hasher = Self {
+            state: State::constant_length(N),
+            arity: Some(N),
+            ..Self::new(r_f, r_p)
+        };
+        hasher.update(&inputs);
+        hasher.squeeze()
+    }
+
+    /// Hashes `salt` followed by `inputs` with a clear state hasher. Salt is
+    /// chosen per invocation, for example to hide committed inputs, whereas a
+    /// domain separator is fixed per protocol and distinguishes different
+    /// uses of the hash. Salt is absorbed as a regular input, so the result is
+    /// the same as hashing `inputs` prepended with `salt`
+    pub fn hash_salted(salt: F, inputs: &[F], r_f: usize, r_p: usize) -> F {
+        let mut hasher = Self::new(r_f, r_p);
+        hasher.update(&[salt]);
+        hasher.update(inputs);
+        hasher.squeeze()
+    }
+
+    /// Hashes `inputs` followed by a counter starting from zero with a clear
+    /// state hasher until `predicate` holds for the result, trying at most
+    /// `max_tries` counters. Result for a counter `i` is the same as hashing
+    /// `inputs` appended with `F::from(i)`, so it is deterministic given the
+    /// same inputs and predicate. Returns `None` if no counter within the
+    /// limit gives an accepted result
+    pub fn hash_until(
+        inputs: &[F],
+        predicate: impl Fn(F) -> bool,
+        max_tries: usize,
+        r_f: usize,
+        r_p: usize,
+    ) -> Option<F> {
+        let mut prefix = Self::new(r_f, r_p);
+        prefix.update(inputs);
+        (0..max_tries as u64)
+            .map(|counter| {
+                let mut hasher = prefix.clone();
+                hasher.update(&[F::from(counter)]);
+                hasher.squeeze()
+            })
+            .find(|output| predicate(*output))
+    }
+
+    /// Hashes `inputs` absorbed back to front with a clear state hasher, so
+    /// the result equals hashing the reversed slice. It is only needed to
+    /// match implementations that absorb in reverse order, for example ones
+    /// that pop inputs from a stack, and should not be mixed with the regular
+    /// order within a protocol
+    pub fn hash_reversed(inputs: &[F], r_f: usize, r_p: usize) -> F {
+        let reversed = inputs.iter().rev().copied().collect::<Vec<F>>();
+        let mut hasher = Self::new(r_f, r_p);
+        hasher.update(&reversed);
+        hasher.squeeze()
+    }
+
+    /// Hashes `inputs` with the length as domain tag. Capacity element is set
+    /// to `2**64 * inputs.len() + 2**63` as in `new_fixed_length` before
+    /// absorbing, so inputs of different lengths never share the initial
+    /// state even if zero padding makes their last chunks equal, and no
+    /// length shares it with the variable length hashing
+    pub fn hash_tagged(inputs: &[F], r_f: usize, r_p: usize) -> F {
+        let mut hasher = Self::new_fixed_length(inputs.len(), r_f, r_p);
+        hasher.update(inputs);
+        hasher.squeeze()
+    }
+
+    /// Hashes each contiguous window of `window` elements of `data` with
+    /// `hash_tagged`. Output has
+    /// `data.len() - window + 1` hashes in the order of window start, or none
+    /// if `data` is shorter than a window. Constants are generated once,
+    /// however each window is absorbed from a clear state, since windows
+    /// starting at different offsets never share absorbed chunks. Reusing
+    /// state would require a construction that hashes `RATE` aligned chunks
+    /// once and combines them per window. Panics if `window` is zero
+    pub fn sliding_window_hashes(data: &[F], window: usize, r_f: usize, r_p: usize) -> Vec<F> {
+        let hasher = Self::new_fixed_length(window, r_f, r_p);
+        data.windows(window)
+            .map(|inputs| {
+                let mut hasher = hasher.clone();
+                hasher.update(inputs);
+                hasher.squeeze()
+            })
+            .collect()
+    }
+
+    /// Fiat-Shamir challenge of a ring signature computed with a clear state
+    /// hasher. Absorbed in order are the number of ring members, the members
+    /// in the given order, `message`, the number of commitments and the
+    /// commitments, so signer and verifier must agree on the order of the
+    /// ring
+    pub fn ring_challenge(ring: &[F], message: F, commitments: &[F], r_f: usize, r_p: usize) -> F {
+        let mut hasher = Self::new(r_f, r_p);
+        hasher.update(&[F::from(ring.len() as u64)]);
+        hasher.update(ring);
+        hasher.update(&[message, F::from(commitments.len() as u64)]);
+        hasher.update(commitments);
+        hasher.squeeze()
+    }
+
+    /// Hashes `inputs` with a clear state hasher and compares the result with
+    /// `expected` in constant time
+    pub fn verify_hash(inputs: &[F], expected: F, r_f: usize, r_p: usize) -> bool {
+        let mut hasher = Self::new(r_f, r_p);
+        hasher.update(inputs);
+        hasher.squeeze().ct_eq(&expected).into()
+    }
+
+    /// Hashes `inputs` with a clear state hasher and returns the big endian
+    /// representation of the digest as in `FixedOutput`, so it can be used as
+    /// a content address key in maps with the full strength of the hash
+    /// instead of a truncated `core::hash::Hasher` output. Representation is
+    /// zero padded or truncated to 32 bytes
+    pub fn map_key(inputs: &[F], r_f: usize, r_p: usize) -> [u8; 32] {
+        let mut hasher = Self::new(r_f, r_p);
+        hasher.update(inputs);
+        let digest = hasher.squeeze();
+        let mut be = vec![0; digest.to_repr().as_ref().len()];
+        write_be(&digest, &mut be);
+        let len = be.len().min(32);
+        let mut key = [0; 32];
+        key[32 - len..].copy_from_slice(&be[be.len() - len..]);
+        key
+    }
+
+    /// Hashes `inputs` with a clear state hasher and returns the digest
+    /// together with the tag `Spec::hash_two(digest, key)`. Anyone can
+    /// recompute the digest while only holders of `key` can produce or check
+    /// the tag, so it is a lightweight MAC as long as the key is secret and
+    /// uniformly random. Tag is a fixed length hash of two elements, so it is
+    /// domain separated from the variable length digest
+    pub fn hash_with_tag(inputs: &[F], key: F, r_f: usize, r_p: usize) -> (F, F) {
+        let mut hasher = Self::new(r_f, r_p);
+        hasher.update(inputs);
+        let digest = hasher.squeeze();
+        (digest, hasher.spec.hash_two(digest, key))
+    }
+
+    /// Checks in constant time that `tag` is the tag of `digest` under `key`
+    /// as returned by `hash_with_tag`
+    pub fn verify_tag(digest: F, tag: F, key: F, r_f: usize, r_p: usize) -> bool {
+        let spec = Spec::<F, T, RATE>::new(r_f, r_p);
+        spec.hash_two(digest, key).ct_eq(&tag).into()
+    }
+
+    /// Hashes `inputs` with a clear state hasher if it takes at most
+    /// `max_permutations` permutations as predicted by `permutation_count`.
+    /// Budget is checked before the spec is constructed, so oversized inputs
+    /// are rejected without doing any work
+    pub fn hash_metered(
+        inputs: &[F],
+        max_permutations: usize,
+        r_f: usize,
+        r_p: usize,
+    ) -> Result<F, Error> {
+        let required = Self::permutation_count(inputs.len());
+        if required > max_permutations {
+            return Err(Error::PermutationBudgetExceeded {
+                required,
+                budget: max_permutations,
+            });
+        }
+        let mut hasher = Self::new(r_f, r_p);
+        hasher.update(inputs);
+        Ok(hasher.squeeze())
+    }
+
+    /// Hashes a string into a field element with a clear state hasher. UTF-8
+    /// bytes of the string are absorbed packed into elements of
+    /// `floor(CAPACITY / 8)` bytes, each chunk read as a little endian integer
+    /// and the last one possibly shorter, and then the length of the string in
+    /// bytes is absorbed
+    pub fn hash_str(s: &str, r_f: usize, r_p: usize) -> F {
+        Self::hash_bytes(s.as_bytes(), r_f, r_p)
+    }
+
+    /// Serializes `value` with `bincode` and hashes the bytes as in
+    /// `hash_str`. Digest is only as stable as the serialized form, so the
+    /// type layout and the serialization format must not change for digests
+    /// to remain comparable
+    #[cfg(feature = "bincode")]
+    pub fn hash_serializable<V: serde::Serialize>(
+        value: &V,
+        r_f: usize,
+        r_p: usize,
+    ) -> Result<F, Error> {
+        let bytes = bincode::serialize(value).map_err(|e| Error::Serialization {
+            message: e.to_string(),
+        })?;
+        Ok(Self::hash_bytes(&bytes, r_f, r_p))
+    }
+
+    fn hash_bytes(bytes: &[u8], r_f: usize, r_p: usize) -> F {
+        let mut hasher = Self::new(r_f, r_p);
+        hasher.update_bytes_le(bytes);
+        hasher.squeeze()
+    }
+
+    /// Applies the hash to `seed` `length` times, that is computes
+    /// `H(H(...H(seed)))` where `H` hashes a single element. Computation is
+    /// inherently sequential
+    pub fn hash_chain(seed: F, length: u64, r_f: usize, r_p: usize) -> F {
+        let mut hasher = Self::new(r_f, r_p);
+        (0..length).fold(seed, |value, _| {
+            hasher.update(&[value]);
+            hasher.squeeze_and_reset()
+        })
+    }
+
+    /// Same as `hash_chain` but also returns the values after every
+    /// `interval` applications so that the chain can be verified in segments.
+    /// Last element is the end of the chain, which is also a checkpoint if
+    /// `length` is a multiple of `interval`
+    pub fn hash_chain_with_checkpoints(
+        seed: F,
+        length: u64,
+        interval: u64,
+        r_f: usize,
+        r_p: usize,
+    ) -> (Vec<F>, F) {
+        assert!(interval > 0);
+        let mut hasher = Self::new(r_f, r_p);
+        let (mut checkpoints, mut until_checkpoint) = (Vec::new(), interval);
+        let end = (0..length).fold(seed, |value, _| {
+            hasher.update(&[value]);
+            let value = hasher.squeeze_and_reset();
+            until_checkpoint -= 1;
+            if until_checkpoint == 0 {
+                checkpoints.push(value);
+                until_checkpoint = interval;
+            }
+            value
+        });
+        (checkpoints, end)
+    }
+}
+
+// Hashing itself only requires a prime field. Constructors above that generate
+// constants with `Grain` require `FromUniformBytes<64>`, while
+// `new_with_uniform_bytes` works with any `FromUniformBytes<N>`
+impl<F: PrimeField, const T: usize, const RATE: usize> Poseidon<F, T, RATE> {
+    /// Constructs a clear state poseidon instance for fields that sample
+    /// round constants from `N` uniform bytes, for example the ones
+    /// implementing `FromUniformBytes<32>` rather than `FromUniformBytes<64>`.
+    /// Yields the same constants as `new` for fields implementing both
+    pub fn new_with_uniform_bytes<const N: usize>(r_f: usize, r_p: usize) -> Self
+    where
+        F: FromUniformBytes<N>,
+    {
+        Self::from_spec(Spec::new_with_uniform_bytes::<N>(r_f, r_p, DEFAULT_ALPHA).unwrap())
+    }
+
+    /// Constructs a clear state poseidon instance with given spec. Specs are
+    /// cheap to clone compared to `Spec::new`, so a spec built once can be
+    /// shared by many hashers
+    pub fn from_spec(spec: Spec<F, T, RATE>) -> Self {
         Self {
-            spec: Spec::new(r_f, r_p),
+            spec,
             state: State::default(),
             absorbing: Vec::new(),
+            absorbed: 0,
+            arity: None,
+            finalized: false,
+            result_mode: ResultMode::Single,
+            pending_bytes: Vec::new(),
+            absorbed_bytes: None,
+            domain: None,
+            checksum: None,
+            message_length: None,
+            squeeze_rate: RATE,
+            #[cfg(feature = "debug-transcript")]
+            transcript: Vec::new(),
         }
     }
 
+    /// Sets how the output is extracted from the state
+    pub fn with_result_mode(mut self, result_mode: ResultMode) -> Self {
+        self.result_mode = result_mode;
+        self
+    }
+
+    /// Sets the number of rate elements `squeeze_n` reads per permutation,
+    /// independent of the absorb rate which is always `RATE`. Default is
+    /// `RATE`. Reading fewer elements keeps the rest of the rate hidden as
+    /// extra capacity, so it costs more permutations per output but doesn't
+    /// weaken the sponge. Reading more than `RATE` would expose the capacity
+    /// element, so the squeeze rate must be in `1..=RATE` and it panics
+    /// otherwise. It only applies to `ResultMode::Single`
+    pub fn with_squeeze_rate(mut self, squeeze_rate: usize) -> Self {
+        assert!(
+            (1..=RATE).contains(&squeeze_rate),
+            "squeeze rate must be in 1..=RATE"
+        );
+        self.squeeze_rate = squeeze_rate;
+        self
+    }
+
+    /// Enables a CRC-64 checksum over the canonical representations of
+    /// elements given to `update`, see `checksum`
+    pub fn with_checksum(mut self) -> Self {
+        self.checksum = Some(CRC64_INIT);
+        self
+    }
+
     /// Appends elements to the absorption line updates state while `RATE` is
     /// full
     pub fn update(&mut self, elements: &[F]) {
+        self.absorbed += elements.len();
+        #[cfg(feature = "debug-transcript")]
+        self.log_transcript(TRANSCRIPT_ABSORB, elements);
+        if let Some(checksum) = self.checksum.as_mut() {
+            for element in elements.iter() {
+                *checksum = crc64(*checksum, element.to_repr().as_ref());
+            }
+        }
+        self.finalized = false;
         let mut input_elements = self.absorbing.clone();
         input_elements.extend_from_slice(elements);
 
@@ -45,8 +473,379 @@ impl<F: FromUniformBytes<64>, const T: usize, const RATE: usize> Poseidon<F, T,
         }
     }
 
-    /// Results a single element by absorbing already added inputs
+    /// Absorbs each slice in order as if they were concatenated and passed to
+    /// `update`, without building the combined input
+    pub fn update_many(&mut self, slices: &[&[F]]) {
+        for elements in slices.iter() {
+            self.update(elements);
+        }
+    }
+
+    /// Pads inputs waiting in the absorption line with zeros up to the rate and
+    /// permutes, so that following inputs start a fresh chunk. Permutation is
+    /// applied even if the absorption line is empty. Flushing changes the
+    /// final digest compared to not flushing, however it is equivalent to
+    /// absorbing zeros up to the end of the chunk, or a full chunk of zeros if
+    /// the line is empty, so frames should be made unambiguous by other means,
+    /// for example by absorbing their lengths
+    pub fn flush(&mut self) {
+        for (input_element, state) in self.absorbing.iter().zip(self.state.0.iter_mut().skip(1)) {
+            state.add_assign(input_element);
+        }
+        self.spec.permute(&mut self.state);
+        self.absorbing.clear();
+        self.finalized = false;
+    }
+
+    /// Appends `u128` values to the absorption line. Each value is encoded as a
+    /// single field element `lo + hi * 2^64` where `lo` and `hi` are its lower
+    /// and upper 64 bit limbs. Field must be able to hold 128 bits so that the
+    /// encoding is not reduced
+    pub fn update_u128(&mut self, values: &[u128]) {
+        assert!(F::CAPACITY >= 128, "field can't hold 128 bit values");
+        let shift = F::from_u128(1 << 64);
+        let elements = values
+            .iter()
+            .map(|value| F::from((value >> 64) as u64) * shift + F::from(*value as u64))
+            .collect::<Vec<F>>();
+        self.update(&elements);
+    }
+
+    /// Absorbs `bytes` packed into elements of `floor(CAPACITY / 8)` bytes,
+    /// each chunk read as a little endian integer and the last one possibly
+    /// shorter, and then the length of `bytes`. Layout is the same as in
+    /// `hash_str`, `hash_reader` and the `digest` integration. Bytes pending
+    /// from `digest::Update` are finished first
+    pub fn update_bytes_le(&mut self, bytes: &[u8]) {
+        self.finish_bytes();
+        self.absorbed_bytes = Some(bytes.len() as u64);
+        self.pending_bytes.extend_from_slice(bytes);
+        self.finish_bytes();
+    }
+
+    /// Same as `update_bytes_le` but each chunk is read as a big endian
+    /// integer, for bytes coming from big endian sources
+    pub fn update_bytes_be(&mut self, bytes: &[u8]) {
+        self.finish_bytes();
+        self.absorbed_bytes = Some(bytes.len() as u64);
+        self.pending_bytes.extend_from_slice(bytes);
+        self.finish_bytes_with(pack_bytes_be);
+    }
+
+    /// Absorbs a digest of another hash function such as SHA256. Absorbed
+    /// elements are the tag `0x646967657374`, which is ASCII `digest`, the
+    /// length of the digest in bytes and the digest bytes packed into elements
+    /// as in `hash_str`. Tag separates external digests from native inputs
+    /// unless they deliberately start with the same tag and length
+    pub fn absorb_external_digest(&mut self, digest: &[u8]) {
+        self.update(&[F::from(EXTERNAL_DIGEST_TAG), F::from(digest.len() as u64)]);
+        self.update(&pack_bytes(digest));
+    }
+
+    /// Resets the hasher and absorbs a message header, that is `message_type`
+    /// and the declared `length` of the message as the first two elements.
+    /// Exactly `length` elements must be absorbed before the next squeeze,
+    /// otherwise `try_squeeze` fails and `squeeze` panics, which catches
+    /// truncated and overlong messages. Squeezing ends the message
+    pub fn begin_message(&mut self, message_type: u64, length: u64) {
+        self.reset();
+        self.update(&[F::from(message_type), F::from(length)]);
+        self.message_length = Some(length as usize);
+    }
+
+    /// Binds the transcript to an external context such as a block number or
+    /// a chain id, so challenges can't be replayed under another context.
+    /// Absorbed elements are the tag `0x636f6e74657874`, which is ASCII
+    /// `context`, the number of context elements and the context itself.
+    /// Context must be bound before any protocol data, so it fails if the
+    /// hasher has already absorbed elements or bytes or has been squeezed
+    pub fn bind_context(&mut self, context: &[F]) -> Result<(), Error> {
+        if self.absorbed > 0 || self.absorbed_bytes.is_some() || self.finalized {
+            return Err(Error::ContextNotFirst {
+                absorbed: self.absorbed,
+            });
+        }
+        self.update(&[F::from(CONTEXT_TAG), F::from(context.len() as u64)]);
+        self.update(context);
+        Ok(())
+    }
+
+    /// Samples a random element with `rng`, absorbs it and returns it. It adds
+    /// entropy to following challenges without changing the statement, for
+    /// example to blind a transcript used as a hiding commitment. Prover must
+    /// send the returned blinding to the verifier separately so that the
+    /// verifier can absorb it at the same position with `update`
+    #[cfg(feature = "rng")]
+    pub fn absorb_blinding<R: RngCore>(&mut self, rng: R) -> F {
+        let blinding = F::random(rng);
+        self.update(&[blinding]);
+        blinding
+    }
+
+    /// Absorbs public inputs as the tag `0x7075626c6963`, which is ASCII
+    /// `public`, the number of inputs and the inputs. Together with
+    /// `absorb_private` it separates the two categories, so a circuit
+    /// replicating the transcript has to agree on which inputs are public
+    pub fn absorb_public(&mut self, inputs: &[F]) {
+        self.update(&[F::from(PUBLIC_TAG), F::from(inputs.len() as u64)]);
+        self.update(inputs);
+    }
+
+    /// Absorbs private inputs as in `absorb_public` but with the tag
+    /// `0x70726976617465`, which is ASCII `private`
+    pub fn absorb_private(&mut self, inputs: &[F]) {
+        self.update(&[F::from(PRIVATE_TAG), F::from(inputs.len() as u64)]);
+        self.update(inputs);
+    }
+
+    /// Absorbs domain separated payloads in order. For each item the domain
+    /// tag is absorbed first, then the length of the payload and then the
+    /// payload itself. Length makes the boundaries between items unambiguous
+    pub fn absorb_domained(&mut self, items: &[(F, &[F])]) {
+        for (domain, payload) in items.iter() {
+            self.update(&[*domain, F::from(payload.len() as u64)]);
+            self.update(payload);
+        }
+    }
+
+    /// Absorbs a Merkle inclusion claim so that prover and verifier transcripts
+    /// are bound to it. Absorbed elements are in order `root`, `leaf`, the
+    /// number of path entries and then for each entry from the leaf level up
+    /// to the root the sibling followed by its direction flag as zero or one.
+    /// Direction is absorbed as given and not interpreted
+    pub fn absorb_inclusion(&mut self, root: F, leaf: F, path: &[(F, bool)]) {
+        self.update(&[root, leaf, F::from(path.len() as u64)]);
+        for (sibling, direction) in path.iter() {
+            self.update(&[*sibling, F::from(*direction as u64)]);
+        }
+    }
+
+    /// Results a single element by absorbing already added inputs. Same as
+    /// the first element of `squeeze_n(1)`
+    ///
+    /// Panics if the hasher is constructed with `new_fixed_length` and number
+    /// of absorbed elements doesn't match the arity, or if the length declared
+    /// with `begin_message` doesn't match. Use `try_squeeze` to handle these
+    /// cases
     pub fn squeeze(&mut self) -> F {
+        self.squeeze_n(1)[0]
+    }
+
+    /// Results a single element by absorbing already added inputs. Fails if
+    /// the hasher is constructed with `new_fixed_length` and number of
+    /// absorbed elements doesn't match the arity, or if the number of elements
+    /// following `begin_message` doesn't match the declared length
+    pub fn try_squeeze(&mut self) -> Result<F, Error> {
+        self.try_squeeze_n(1).map(|outputs| outputs[0])
+    }
+
+    /// Squeezes `n` elements. The first squeeze after absorbing applies the
+    /// padding and the finishing permutation, then up to the squeeze rate,
+    /// `RATE` unless set with `with_squeeze_rate`, elements are
+    /// read from the rate part of the state and the state is permuted again
+    /// whenever more are requested. Further calls without a new `update` only
+    /// permute the state once more before reading, the padding is not applied
+    /// again. `squeeze_n(0)` still applies the finishing permutation. With
+    /// `ResultMode::Sum` every output is the sum of the rate and the state is
+    /// permuted between outputs. Unlike `challenge_vector` outputs are not
+    /// absorbed back
+    ///
+    /// Panics under the same conditions as `squeeze`
+    pub fn squeeze_n(&mut self, n: usize) -> Vec<F> {
+        self.try_squeeze_n(n).unwrap()
+    }
+
+    fn try_squeeze_n(&mut self, n: usize) -> Result<Vec<F>, Error> {
+        if self.finalized {
+            self.spec.permute(&mut self.state);
+        } else {
+            if let Some(length) = self.message_length {
+                // Header is the first two absorbed elements
+                let actual = self.absorbed - 2;
+                if actual != length {
+                    return Err(Error::InvalidInputLength {
+                        expected: length,
+                        actual,
+                    });
+                }
+            }
+            match self.arity {
+                Some(arity) => self.finish_fixed_length(arity)?,
+                None => self.finish_variable_length(),
+            }
+            self.finalized = true;
+            self.message_length = None;
+        }
+
+        let mut outputs = Vec::with_capacity(n);
+        while outputs.len() < n {
+            if !outputs.is_empty() {
+                self.spec.permute(&mut self.state);
+            }
+            match self.result_mode {
+                ResultMode::Single => {
+                    let remaining = (n - outputs.len()).min(self.squeeze_rate);
+                    outputs.extend(self.state.0.iter().skip(1).take(remaining))
+                }
+                ResultMode::Sum => outputs.push(self.result()),
+            }
+        }
+        #[cfg(feature = "debug-transcript")]
+        self.log_transcript(TRANSCRIPT_SQUEEZE, &outputs);
+        Ok(outputs)
+    }
+
+    /// Squeezes a challenge and absorbs it back without resetting the state,
+    /// so that following challenges depend on it
+    pub fn challenge_and_absorb(&mut self) -> F {
+        let challenge = self.squeeze();
+        self.update(&[challenge]);
+        challenge
+    }
+
+    /// Squeezes `n` challenges with `challenge_and_absorb`. It is the
+    /// transcript analog of squeezing `n` elements: each challenge is absorbed
+    /// back, so challenges depend on all previous ones and the hasher remains
+    /// usable for further absorption. Use `squeeze_n` to read several
+    /// elements from the rate without absorbing them back
+    pub fn challenge_vector(&mut self, n: usize) -> Vec<F> {
+        (0..n).map(|_| self.challenge_and_absorb()).collect()
+    }
+
+    /// Squeezes `n` public inputs with a permutation per output. First output
+    /// is the regular `squeeze`, each next one permutes the state again and
+    /// extracts the result the same way. This matches circuits that squeeze
+    /// by permuting for every public input, unlike `squeeze_n` which reads up
+    /// to `RATE` elements per permutation and `challenge_vector` which absorbs
+    /// each output back as a new input
+    pub fn public_inputs(&mut self, n: usize) -> Vec<F> {
+        let mut outputs = Vec::with_capacity(n);
+        if n > 0 {
+            outputs.push(self.squeeze());
+        }
+        while outputs.len() < n {
+            self.spec.permute(&mut self.state);
+            outputs.push(self.result());
+            #[cfg(feature = "debug-transcript")]
+            self.log_transcript(TRANSCRIPT_SQUEEZE, &outputs[outputs.len() - 1..]);
+        }
+        outputs
+    }
+
+    /// Squeezes a single element and keeps only its `BITS` least significant
+    /// bits so that the output fits into a smaller field. Note that the field
+    /// order is not a power of two, so the masked output is biased with
+    /// statistical distance of at most `2^BITS / p` from uniform. Expects
+    /// little endian field representation
+    pub fn squeeze_mod<const BITS: usize>(&mut self) -> F {
+        assert!(BITS <= F::NUM_BITS as usize);
+        let mut repr = self.squeeze().to_repr();
+        for (i, byte) in repr.as_mut().iter_mut().enumerate() {
+            let bits = BITS.saturating_sub(8 * i).min(8);
+            *byte &= ((1u16 << bits) - 1) as u8;
+        }
+        F::from_repr(repr).unwrap()
+    }
+
+    /// Squeezes a single element and returns its 31 least significant bytes
+    /// in big endian order. Most significant byte of the representation is
+    /// dropped, so the output is the result modulo `2^248` which always fits
+    /// into a field element of at least 249 bits. Expects little endian field
+    /// representation
+    pub fn squeeze_bytes_31(&mut self) -> [u8; 31] {
+        let repr = self.squeeze().to_repr();
+        let bytes = repr.as_ref();
+        assert!(bytes.len() >= 31);
+        core::array::from_fn(|i| bytes[30 - i])
+    }
+
+    /// Squeezes two elements `lo` and `hi` and reduces the integer
+    /// `lo + p * hi` into the scalar field `S` with `from_uniform_bytes`,
+    /// where `p` is the modulus of `F`. Integer is uniform in `[0, p^2)`, so
+    /// the bias of the reduction is about `q / p^2` for a scalar field of
+    /// order `q`, which is negligible when `q` is not much larger than `p`.
+    /// Field `F` must have a 32 byte little endian representation
+    pub fn squeeze_scalar<S: FromUniformBytes<64>>(&mut self) -> S
+    where
+        F: ReprSize<Size = U32>,
+    {
+        let elements = self.squeeze_n(2);
+        S::from_uniform_bytes(&wide_bytes(elements[0], elements[1]))
+    }
+
+    /// Squeezes a single element and returns its representation as a 32 byte
+    /// seed for byte seeded RNGs such as ChaCha, so shuffling driven by the
+    /// seed is reproducible from the transcript. Representation is zero
+    /// padded or truncated to 32 bytes. For a 254 bit field the top bits are
+    /// always zero, which leaves plenty of entropy for seeding
+    pub fn shuffle_seed(&mut self) -> [u8; 32] {
+        let repr = self.squeeze().to_repr();
+        let bytes = repr.as_ref();
+        let mut seed = [0; 32];
+        let len = bytes.len().min(32);
+        seed[..len].copy_from_slice(&bytes[..len]);
+        seed
+    }
+
+    /// Squeezes a single element like `squeeze` and also returns the
+    /// capacity element of the state after the final permutation. Capacity is
+    /// otherwise hidden from the output, so this is meant for diagnosing
+    /// where two hashers diverge and not for deriving values
+    pub fn squeeze_debug(&mut self) -> (F, F) {
+        let output = self.squeeze();
+        (output, self.state.0[0])
+    }
+
+    /// Returns the log of every element given to `update` and every squeezed
+    /// element in order since construction or the last `reset`, for
+    /// replaying the transcript in another implementation. Each entry is a
+    /// marker byte, `0` for absorbed and `1` for squeezed elements, followed
+    /// by the canonical representation of the element. Elements absorbed
+    /// through `digest::Update` are logged once they are packed. Log is not
+    /// serialized with the hasher
+    #[cfg(feature = "debug-transcript")]
+    pub fn transcript_bytes(&self) -> Vec<u8> {
+        self.transcript.clone()
+    }
+
+    #[cfg(feature = "debug-transcript")]
+    fn log_transcript(&mut self, marker: u8, elements: &[F]) {
+        for element in elements.iter() {
+            self.transcript.push(marker);
+            self.transcript
+                .extend_from_slice(element.to_repr().as_ref());
+        }
+    }
+
+    fn result(&self) -> F {
+        match self.result_mode {
+            ResultMode::Single => self.state.result(),
+            ResultMode::Sum => self.state.0.iter().skip(1).sum(),
+        }
+    }
+
+    fn finish_fixed_length(&mut self, arity: usize) -> Result<(), Error> {
+        if self.absorbed != arity {
+            return Err(Error::InvalidInputLength {
+                expected: arity,
+                actual: self.absorbed,
+            });
+        }
+
+        // Inputs that fill the rate are already permuted in the absorption line.
+        // Remaining inputs are implicitly padded with zeros
+        if !self.absorbing.is_empty() || arity == 0 {
+            for (input_element, state) in self.absorbing.iter().zip(self.state.0.iter_mut().skip(1))
+            {
+                state.add_assign(input_element);
+            }
+            self.spec.permute(&mut self.state);
+            self.absorbing.clear();
+        }
+        Ok(())
+    }
+
+    fn finish_variable_length(&mut self) {
         let mut last_chunk = self.absorbing.clone();
         {
             // Expect padding offset to be in [0, RATE)
@@ -65,14 +864,26 @@ impl<F: FromUniformBytes<64>, const T: usize, const RATE: usize> Poseidon<F, T,
         self.spec.permute(&mut self.state);
         // Flush the absorption line
         self.absorbing.clear();
-        // Returns the challenge while preserving internal state
-        self.state.result()
     }
 
     /// Resets the internal state
     pub fn reset(&mut self) {
-        self.state = State::default();
+        self.state = match (self.domain, self.arity) {
+            (Some(domain), _) => State::with_capacity(domain),
+            (None, Some(arity)) => State::fixed_length(arity),
+            (None, None) => State::default(),
+        };
         self.absorbing.clear();
+        self.absorbed = 0;
+        self.finalized = false;
+        self.pending_bytes.clear();
+        self.absorbed_bytes = None;
+        if self.checksum.is_some() {
+            self.checksum = Some(CRC64_INIT);
+        }
+        self.message_length = None;
+        #[cfg(feature = "debug-transcript")]
+        self.transcript.clear();
     }
 
     /// Squeezes and resets the internal state making the hasher stateless
@@ -81,18 +892,243 @@ impl<F: FromUniformBytes<64>, const T: usize, const RATE: usize> Poseidon<F, T,
         self.reset();
         result
     }
+
+    /// Hashes `N` elements with the variable length padding, which is the same
+    /// as absorbing them into a clear state hasher with `update` and
+    /// squeezing once, without allocating. Arity is checked at compile time to
+    /// be at most `8 * RATE`
+    ///
+    /// ```compile_fail
+    /// use halo2curves_axiom::bn256::Fr;
+    /// use pse_poseidon::{Poseidon, Spec};
+    ///
+    /// let spec = Spec::<Fr, 3, 2>::new(8, 57);
+    /// Poseidon::hash_n(&[Fr::from(1); 17], &spec);
+    /// ```
+    pub fn hash_n<const N: usize>(inputs: &[F; N], spec: &Spec<F, T, RATE>) -> F {
+        let () = HashNArity::<N, RATE>::CHECK;
+        let mut state = State::default();
+        let mut chunks = inputs.chunks_exact(RATE);
+        for chunk in chunks.by_ref() {
+            for (word, input) in state.0.iter_mut().skip(1).zip(chunk.iter()) {
+                *word += input;
+            }
+            spec.permute(&mut state);
+        }
+        let padding = [F::ONE];
+        let last_chunk = chunks.remainder().iter().chain(padding.iter());
+        for (word, input) in state.0.iter_mut().skip(1).zip(last_chunk) {
+            *word += input;
+        }
+        spec.permute(&mut state);
+        state.result()
+    }
+
+    /// Hashes each input independently as in `hash_tagged`, that is with the
+    /// length of each input as domain tag. Spec is shared and the state lives
+    /// on the stack, so nothing is allocated besides the output
+    pub fn hash_batch(inputs: &[Vec<F>], spec: &Spec<F, T, RATE>) -> Vec<F> {
+        inputs
+            .iter()
+            .map(|inputs| {
+                let mut state = State::fixed_length(inputs.len());
+                for chunk in inputs.chunks(RATE) {
+                    for (word, input) in state.0.iter_mut().skip(1).zip(chunk.iter()) {
+                        *word += input;
+                    }
+                    spec.permute(&mut state);
+                }
+                if inputs.is_empty() {
+                    spec.permute(&mut state);
+                }
+                state.result()
+            })
+            .collect()
+    }
+
+    /// Number of permutations a clear state variable length hasher applies to
+    /// absorb `length` elements and squeeze once. Every full chunk of `RATE`
+    /// elements is permuted while absorbing and the padded last chunk is
+    /// permuted when squeezing
+    pub fn permutation_count(length: usize) -> usize {
+        length / RATE + 1
+    }
+
+    /// CRC-64/XZ checksum of the canonical representations of all elements
+    /// absorbed since construction or the last `reset`, or `None` if it isn't
+    /// enabled with `with_checksum`. It is cheap and not cryptographic, meant
+    /// for detecting transmission errors before verifying the digest
+    pub fn checksum(&self) -> Option<u64> {
+        self.checksum.map(|checksum| checksum ^ !0)
+    }
+
+    /// Returns true if both hashers yield the same outputs for any future
+    /// inputs. Besides the states it compares specs, that is number of rounds,
+    /// round constants and MDS matrices, inputs waiting in the absorption line,
+    /// whether the hasher is already squeezed, and the number of absorbed
+    /// elements when it is checked on squeezing, that is with fixed length
+    /// arity or a length declared with `begin_message`
+    pub fn same_config(&self, other: &Self) -> bool {
+        self.spec == other.spec
+            && self.state == other.state
+            && self.absorbing == other.absorbing
+            && self.arity == other.arity
+            && self.result_mode == other.result_mode
+            && self.domain == other.domain
+            && self.pending_bytes == other.pending_bytes
+            && self.absorbed_bytes == other.absorbed_bytes
+            && self.message_length == other.message_length
+            && self.squeeze_rate == other.squeeze_rate
+            && self.finalized == other.finalized
+            && ((self.arity.is_none() && self.message_length.is_none())
+                || self.absorbed == other.absorbed)
+    }
+
+    /// Hashes the current state words into a single element with a clear
+    /// state hasher of the same spec. It is meant for debugging only, for
+    /// example to log and compare states of diverging implementations. Inputs
+    /// waiting in the absorption line are not covered
+    pub fn state_fingerprint(&self) -> F {
+        let mut hasher = Self::from_spec(self.spec.clone());
+        hasher.update(&self.state.0);
+        hasher.squeeze()
+    }
+
+    /// Derives a child hasher for tree structured derivation. The state
+    /// words and the absorption line of this hasher followed by `index` are
+    /// hashed with a clear state hasher of the same spec, and the child is a
+    /// clear state hasher that has absorbed the result. So the child is bound
+    /// to both the inputs the parent has absorbed so far and the index, while
+    /// it doesn't inherit the configuration of the parent such as the arity.
+    /// Pending bytes of the `digest` integration are not covered. This
+    /// hasher is left untouched
+    pub fn derive_child(&self, index: u64) -> Self {
+        let mut binding = Self::from_spec(self.spec.clone());
+        binding.update(&self.state.0);
+        binding.update(&self.absorbing);
+        binding.update(&[F::from(index)]);
+
+        let mut child = Self::from_spec(self.spec.clone());
+        child.update(&[binding.squeeze()]);
+        child
+    }
+
+    /// Hashes `prefix` followed by each of `suffixes` with a clear state
+    /// hasher of `spec`. Prefix is absorbed only once and a clone of the
+    /// resulting hasher finishes each suffix, which saves the permutations of
+    /// the prefix compared to hashing each concatenation independently
+    pub fn hash_batch_shared_prefix(
+        prefix: &[F],
+        suffixes: &[Vec<F>],
+        spec: &Spec<F, T, RATE>,
+    ) -> Vec<F> {
+        let mut prefixed = Self::from_spec(spec.clone());
+        prefixed.update(prefix);
+
+        suffixes
+            .iter()
+            .map(|suffix| {
+                let mut hasher = prefixed.clone();
+                hasher.update(suffix);
+                hasher.squeeze()
+            })
+            .collect()
+    }
+
+    /// Absorbs all bytes of `r` followed by their number as in `hash_str` and
+    /// squeezes the result. Bytes pending from `digest::Update` are finished
+    /// first
+    #[cfg(feature = "std")]
+    pub fn hash_reader<R: Read>(&mut self, r: R) -> io::Result<F> {
+        self.hash_reader_counted(r).map(|(result, _)| result)
+    }
+
+    /// Same as `hash_reader` but also returns the number of bytes consumed
+    /// from `r`
+    #[cfg(feature = "std")]
+    pub fn hash_reader_counted<R: Read>(&mut self, mut r: R) -> io::Result<(F, u64)> {
+        self.finish_bytes();
+        self.absorbed_bytes = Some(0);
+        let chunk_len = bytes_per_element::<F>() as u64;
+        let mut chunk = Vec::with_capacity(chunk_len as usize);
+        let mut count = 0;
+        loop {
+            chunk.clear();
+            let n = r.by_ref().take(chunk_len).read_to_end(&mut chunk)?;
+            Update::update(self, &chunk);
+            count += n as u64;
+            if (n as u64) < chunk_len {
+                break;
+            }
+        }
+        self.finish_bytes();
+        Ok((self.squeeze(), count))
+    }
+
+    /// Absorbs bytes given to `digest::Update` that don't fill an element yet
+    /// and the total number of bytes, if any bytes were given
+    pub(crate) fn finish_bytes(&mut self) {
+        self.finish_bytes_with(pack_bytes)
+    }
+
+    /// Same as `finish_bytes` but pending bytes are packed with `pack`
+    fn finish_bytes_with(&mut self, pack: fn(&[u8]) -> Vec<F>) {
+        if let Some(absorbed_bytes) = self.absorbed_bytes.take() {
+            let elements = pack(&core::mem::take(&mut self.pending_bytes));
+            self.update(&elements);
+            self.update(&[F::from(absorbed_bytes)]);
+        }
+    }
+}
+
+impl<F: FromUniformBytes<64>> Poseidon<F, 3, 2> {
+    /// Constructs a clear state instance with width 3, rate 2 and the
+    /// standard number of rounds `R_F = 8`, `R_P = 57` for 254 bit fields
+    pub fn t3_rate2() -> Self {
+        Self::new(8, 57)
+    }
+}
+
+impl<F: FromUniformBytes<64>> Poseidon<F, 5, 4> {
+    /// Constructs a clear state instance with width 5, rate 4 and the
+    /// standard number of rounds `R_F = 8`, `R_P = 60` for 254 bit fields
+    pub fn t5_rate4() -> Self {
+        Self::new(8, 60)
+    }
 }
 
 impl<F: PrimeField, const T: usize, const RATE: usize> HashMarker for Poseidon<F, T, RATE> {}
 
-impl<F: PrimeField, const T: usize, const RATE: usize> OutputSizeUser for Poseidon<F, T, RATE> {
-    type OutputSize = typenum::U32;
+impl<F: ReprSize, const T: usize, const RATE: usize> OutputSizeUser for Poseidon<F, T, RATE> {
+    type OutputSize = F::Size;
 }
 
-impl<F: FromUniformBytes<64>, const T: usize, const RATE: usize> Update for Poseidon<F, T, RATE> {
+/// Bytes are packed into elements as in `hash_reader`, each
+/// `floor(CAPACITY / 8)` bytes read as a little endian integer. Bytes that
+/// don't fill an element are kept until following updates complete it, so
+/// splitting the input across updates doesn't change the digest. On
+/// finalization the trailing shorter chunk and then the total number of bytes
+/// are absorbed, which makes the digest equal to `hash_reader` over the same
+/// bytes. Empty updates have no effect as required by `digest`, so the digest
+/// of the empty input is the output of a clear hasher rather than
+/// `hash_reader` over no bytes. Bytes and field elements given to
+/// `Poseidon::update` shouldn't be interleaved since pending bytes are only
+/// absorbed once they fill an element
+impl<F: PrimeField, const T: usize, const RATE: usize> Update for Poseidon<F, T, RATE> {
     fn update(&mut self, data: &[u8]) {
-        let data_in_fe = data.iter().map(|v| F::from(*v as u64)).collect::<Vec<F>>();
-        Poseidon::update(self, &data_in_fe);
+        if data.is_empty() {
+            return;
+        }
+        let chunk_len = bytes_per_element::<F>();
+        self.absorbed_bytes = Some(self.absorbed_bytes.unwrap_or(0) + data.len() as u64);
+        self.pending_bytes.extend_from_slice(data);
+
+        let full = self.pending_bytes.len() - self.pending_bytes.len() % chunk_len;
+        if full > 0 {
+            let elements = pack_bytes(&self.pending_bytes[..full]);
+            self.pending_bytes.drain(..full);
+            Poseidon::update(self, &elements);
+        }
     }
 }
 
@@ -107,30 +1143,29 @@ impl<F: PrimeField, const T: usize, const RATE: usize> BlockSizeUser for Poseido
 impl<F: FromUniformBytes<64>, const T: usize, const RATE: usize> Default for Poseidon<F, T, RATE> {
     fn default() -> Self {
         // TODO: Find a way to make this generic, for now we are hardcoding
-        Self {
-            spec: Spec::new(8 as usize, 57 as usize),
-            state: State::default(),
-            absorbing: Vec::new(),
-        }
+        Self::from_spec(Spec::new(8, 57))
     }
 }
 
-impl<F: FromUniformBytes<64>, const T: usize, const RATE: usize> FixedOutput
-    for Poseidon<F, T, RATE>
-{
+/// Output is the big endian representation of the squeezed element, that is
+/// the reversed `PrimeField::Repr` for fields with little endian
+/// representation
+impl<F: ReprSize, const T: usize, const RATE: usize> FixedOutput for Poseidon<F, T, RATE> {
     fn finalize_into(mut self, out: &mut digest::Output<Self>) {
+        debug_assert!(!self.finalized, "poseidon hasher is already finalized");
+        self.finish_bytes();
         let result = self.squeeze_and_reset();
-        let mut result_bytes = result.to_repr().as_ref().to_vec();
-        result_bytes.reverse();
-        out.copy_from_slice(&result_bytes);
+        write_be(&result, out);
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{Poseidon, State};
+    use crate::bytes::{from_hex, pack_bytes};
+    use crate::{Error, Poseidon, ResultMode, Spec, State};
+    use digest::{FixedOutput, Update};
     use halo2curves_axiom::bn256::Fr;
-    use halo2curves_axiom::group::ff::Field;
+    use halo2curves_axiom::group::ff::{Field, PrimeField};
     use paste::paste;
     use rand_core::OsRng;
 
@@ -143,6 +1178,22 @@ mod tests {
         (0..len).map(|_| Fr::random(OsRng)).collect::<Vec<Fr>>()
     }
 
+    /// Reference sponge absorbing `inputs` into a clear state with the
+    /// variable length padding, which gives the state of `update` followed by
+    /// the first squeeze
+    fn absorb_padded(inputs: &[Fr], spec: &Spec<Fr, T, RATE>) -> State<Fr, T> {
+        let mut padded = inputs.to_vec();
+        padded.push(Fr::one());
+        let mut state = State::<Fr, T>::default();
+        for chunk in padded.chunks(RATE) {
+            for (word, input) in state.0.iter_mut().skip(1).zip(chunk.iter()) {
+                *word += input;
+            }
+            spec.permute(&mut state);
+        }
+        state
+    }
+
     #[test]
     fn poseidon_padding_with_last_chunk_len_is_not_rate_multiples() {
         let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
@@ -199,56 +1250,1947 @@ mod tests {
         assert_eq!(result_0, result_1);
     }
 
-    macro_rules! test_padding {
-        ($T:expr, $RATE:expr) => {
-            paste! {
-                #[test]
-                fn [<test_padding_ $T _ $RATE>]() {
-                    for number_of_iters in 1..25 {
-                        let mut poseidon = Poseidon::<Fr, $T, $RATE>::new(R_F, R_P);
+    #[test]
+    fn poseidon_derive_child() {
+        let mut parent = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        parent.update(&gen_random_vec(3));
+        let state = parent.state.clone();
 
-                        let mut inputs = vec![];
-                        for number_of_inputs in 0..=number_of_iters {
-                            let chunk = (0..number_of_inputs)
-                                .map(|_| Fr::random(OsRng))
-                                .collect::<Vec<Fr>>();
-                            poseidon.update(&chunk[..]);
-                            inputs.extend(chunk);
-                        }
-                        let result_0 = poseidon.squeeze();
+        let key = |index: u64| parent.derive_child(index).squeeze();
+        assert_ne!(key(0), key(1));
+        assert_eq!(key(7), key(7));
 
-                        // Accept below as reference and check consistency
-                        inputs.push(Fr::one());
-                        let offset = inputs.len() % $RATE;
-                        if offset != 0 {
-                            inputs.extend(vec![Fr::zero(); $RATE - offset]);
-                        }
+        // Children of a parent with different inputs must differ
+        let mut other = parent.clone();
+        other.update(&gen_random_vec(1));
+        assert_ne!(key(0), other.derive_child(0).squeeze());
 
-                        let spec = poseidon.spec.clone();
-                        let mut state = State::<Fr, $T>::default();
-                        for chunk in inputs.chunks($RATE) {
-                            // First element is zero
-                            let mut round_inputs = vec![Fr::zero()];
-                            // Round inputs must be T sized now
-                            round_inputs.extend_from_slice(chunk);
+        // Parent is not affected by the derivation
+        assert_eq!(parent.state, state);
 
-                            state.add_constants(&round_inputs.try_into().unwrap());
-                            spec.permute(&mut state)
-                        }
-                        let result_1 = state.result();
-                        assert_eq!(result_0, result_1);
-                    }
-                }
+        // Child is a clear state hasher seeded with the binding
+        let mut binding = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        binding.update(&parent.state.0);
+        binding.update(&parent.absorbing);
+        binding.update(&[Fr::from(3)]);
+        let mut expected = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        expected.update(&[binding.squeeze()]);
+        assert_eq!(key(3), expected.squeeze());
+
+        // Child of a fixed length parent doesn't inherit the arity
+        let mut parent = Poseidon::<Fr, T, RATE>::new_fixed_length(2, R_F, R_P);
+        parent.update(&gen_random_vec(1));
+        let mut child = parent.derive_child(0);
+        assert_eq!(child.arity, None);
+        child.update(&gen_random_vec(RATE));
+        assert_ne!(child.squeeze(), parent.derive_child(1).squeeze());
+        assert_eq!(
+            parent.try_squeeze(),
+            Err(Error::InvalidInputLength {
+                expected: 2,
+                actual: 1,
+            })
+        );
+    }
+
+    #[test]
+    fn poseidon_hash_batch_shared_prefix() {
+        let spec = Spec::<Fr, T, RATE>::new(R_F, R_P);
+        for prefix_len in [0, 1, RATE - 1, RATE, 3 * RATE + 1] {
+            let prefix = gen_random_vec(prefix_len);
+            let suffixes = (0..2 * RATE + 1)
+                .map(gen_random_vec)
+                .collect::<Vec<Vec<Fr>>>();
+
+            let results = Poseidon::hash_batch_shared_prefix(&prefix, &suffixes, &spec);
+            assert_eq!(results.len(), suffixes.len());
+            for (suffix, result) in suffixes.iter().zip(results) {
+                let mut expected = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+                expected.update(&prefix);
+                expected.update(suffix);
+                assert_eq!(result, expected.squeeze());
             }
-        };
+        }
     }
 
-    test_padding!(3, 2);
-    test_padding!(4, 3);
-    test_padding!(5, 4);
+    #[test]
+    fn poseidon_update_u128() {
+        let values = [
+            0u128,
+            u64::MAX as u128,
+            u64::MAX as u128 + 1,
+            0x0123456789abcdef_fedcba9876543210,
+            u128::MAX,
+        ];
+
+        let two_64 = Fr::from(u64::MAX) + Fr::one();
+        let expected = [
+            Fr::zero(),
+            Fr::from(u64::MAX),
+            two_64,
+            Fr::from(0x0123456789abcdef) * two_64 + Fr::from(0xfedcba9876543210),
+            Fr::from(u64::MAX) * two_64 + Fr::from(u64::MAX),
+        ];
+
+        let mut poseidon_0 = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        let mut poseidon_1 = poseidon_0.clone();
+        poseidon_0.update_u128(&values);
+        poseidon_1.update(&expected);
+        assert_eq!(poseidon_0.squeeze(), poseidon_1.squeeze());
+    }
+
+    #[test]
+    fn poseidon_verify_hash() {
+        let inputs = gen_random_vec(RATE + 2);
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&inputs);
+        let expected = poseidon.squeeze();
+
+        assert!(Poseidon::<Fr, T, RATE>::verify_hash(
+            &inputs, expected, R_F, R_P
+        ));
+        assert!(!Poseidon::<Fr, T, RATE>::verify_hash(
+            &inputs,
+            expected + Fr::one(),
+            R_F,
+            R_P
+        ));
+        for i in 0..inputs.len() {
+            let mut tampered = inputs.clone();
+            tampered[i] += Fr::one();
+            assert!(!Poseidon::<Fr, T, RATE>::verify_hash(
+                &tampered, expected, R_F, R_P
+            ));
+        }
+    }
+
+    #[test]
+    fn poseidon_fixed_length() {
+        for arity in 0..=3 * RATE {
+            let inputs = gen_random_vec(arity);
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new_fixed_length(arity, R_F, R_P);
+            poseidon.update(&inputs);
+            let result_0 = poseidon.squeeze();
+
+            // Reference is zero padded to rate multiple without the variable length
+            // finishing sign and at least one permutation is applied
+            let spec = poseidon.spec.clone();
+            let mut state = State::<Fr, T>::fixed_length(arity);
+            let mut inputs = inputs.clone();
+            let offset = arity % RATE;
+            if arity == 0 || offset != 0 {
+                inputs.extend(vec![Fr::zero(); RATE - offset]);
+            }
+            for chunk in inputs.chunks(RATE) {
+                let mut round_inputs = vec![Fr::zero()];
+                round_inputs.extend_from_slice(chunk);
+                state.add_constants(&round_inputs.try_into().unwrap());
+                spec.permute(&mut state)
+            }
+            assert_eq!(result_0, state.result());
+
+            // Reset must restore the arity tagged capacity
+            poseidon.reset();
+            poseidon.update(&inputs[..arity]);
+            assert_eq!(result_0, poseidon.squeeze());
+        }
+
+        // Arity one doesn't share the initial state of the variable length
+        // hashing
+        let input = Fr::random(OsRng);
+        let poseidon = Poseidon::<Fr, T, RATE>::new_fixed_length(1, R_F, R_P);
+        assert_ne!(poseidon.state, State::default());
+        let mut state = State::<Fr, T>::default();
+        state.0[1] = input;
+        poseidon.spec.permute(&mut state);
+        assert_ne!(
+            Poseidon::<Fr, T, RATE>::hash_tagged(&[input], R_F, R_P),
+            state.result()
+        );
+
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new_fixed_length(RATE, R_F, R_P);
+        poseidon.update(&gen_random_vec(RATE - 1));
+        assert_eq!(
+            poseidon.try_squeeze(),
+            Err(Error::InvalidInputLength {
+                expected: RATE,
+                actual: RATE - 1
+            })
+        );
+        poseidon.update(&gen_random_vec(2));
+        assert_eq!(
+            poseidon.try_squeeze(),
+            Err(Error::InvalidInputLength {
+                expected: RATE,
+                actual: RATE + 1
+            })
+        );
+
+        // Fixed length hashing is domain separated from variable length hashing
+        let inputs = gen_random_vec(RATE - 1);
+        let mut poseidon_0 = Poseidon::<Fr, T, RATE>::new_fixed_length(RATE - 1, R_F, R_P);
+        let mut poseidon_1 = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon_0.update(&inputs);
+        poseidon_1.update(&inputs);
+        assert_ne!(poseidon_0.squeeze(), poseidon_1.squeeze());
+    }
+
+    #[test]
+    fn poseidon_squeeze_mod() {
+        fn fits(e: Fr, bits: usize) -> bool {
+            let repr = e.to_repr();
+            (bits..Fr::NUM_BITS as usize).all(|i| repr[i / 8] >> (i % 8) & 1 == 0)
+        }
+
+        for _ in 0..10 {
+            let inputs = gen_random_vec(RATE + 1);
+            let mut poseidon_0 = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            poseidon_0.update(&inputs);
+            let mut poseidon_1 = poseidon_0.clone();
+            let mut poseidon_2 = poseidon_0.clone();
+
+            let e = poseidon_0.squeeze_mod::<1>();
+            assert!(fits(e, 1));
+            let e = poseidon_0.squeeze_mod::<64>();
+            assert!(fits(e, 64));
+            let e = poseidon_0.squeeze_mod::<100>();
+            assert!(fits(e, 100));
+            assert_eq!(e, {
+                poseidon_1.squeeze_mod::<1>();
+                poseidon_1.squeeze_mod::<64>();
+                poseidon_1.squeeze_mod::<100>()
+            });
+
+            // Masking with full width is the identity
+            assert_eq!(poseidon_2.squeeze_mod::<254>(), {
+                let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+                poseidon.update(&inputs);
+                poseidon.squeeze()
+            });
+        }
+    }
+
+    #[test]
+    fn poseidon_hash_two() {
+        let (a, b) = (Fr::random(OsRng), Fr::random(OsRng));
+        let mut poseidon = Poseidon::<Fr, 3, 2>::new_fixed_length(2, R_F, R_P);
+        poseidon.update(&[a, b]);
+        let result_0 = poseidon.squeeze();
+        assert_eq!(result_0, poseidon.spec.hash_two(a, b));
+        assert_ne!(result_0, poseidon.spec.hash_two(b, a));
+
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new_fixed_length(2, R_F, R_P);
+        poseidon.update(&[a, b]);
+        assert_eq!(poseidon.squeeze(), poseidon.spec.hash_two(a, b));
+    }
+
+    #[test]
+    fn poseidon_same_config() {
+        let mut poseidon_0 = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        let mut poseidon_1 = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        assert!(poseidon_0.same_config(&poseidon_1));
+
+        let inputs = gen_random_vec(RATE + 1);
+        poseidon_0.update(&inputs);
+        assert!(!poseidon_0.same_config(&poseidon_1));
+        poseidon_1.update(&inputs);
+        assert!(poseidon_0.same_config(&poseidon_1));
+
+        assert!(!Poseidon::<Fr, T, RATE>::new(R_F, R_P)
+            .same_config(&Poseidon::<Fr, T, RATE>::new(R_F, R_P - 1)));
+        assert!(!Poseidon::<Fr, T, RATE>::new(R_F, R_P)
+            .same_config(&Poseidon::<Fr, T, RATE>::new(R_F + 2, R_P)));
+        assert!(!Poseidon::<Fr, T, RATE>::new(R_F, R_P)
+            .same_config(&Poseidon::<Fr, T, RATE>::new_fixed_length(0, R_F, R_P)));
+
+        // Squeezed hasher only permutes on the next squeeze while an empty
+        // update makes it apply the padding again
+        poseidon_0.squeeze();
+        poseidon_1 = poseidon_0.clone();
+        poseidon_1.update(&[]);
+        assert_eq!(poseidon_0.state, poseidon_1.state);
+        assert!(!poseidon_0.same_config(&poseidon_1));
+        assert_ne!(poseidon_0.squeeze(), poseidon_1.squeeze());
+    }
+
+    #[cfg(feature = "tracing")]
+    #[test]
+    fn poseidon_trace_permutations() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+        use tracing::span::{Attributes, Id, Record};
+        use tracing::{Event, Metadata, Subscriber};
+
+        struct CountingSubscriber(Arc<AtomicUsize>);
+
+        impl Subscriber for CountingSubscriber {
+            fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+                metadata.target().starts_with("pse_poseidon")
+            }
+            fn new_span(&self, _: &Attributes<'_>) -> Id {
+                Id::from_u64(1)
+            }
+            fn record(&self, _: &Id, _: &Record<'_>) {}
+            fn record_follows_from(&self, _: &Id, _: &Id) {}
+            fn event(&self, _: &Event<'_>) {
+                self.0.fetch_add(1, Ordering::SeqCst);
+            }
+            fn enter(&self, _: &Id) {}
+            fn exit(&self, _: &Id) {}
+        }
+
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        for number_of_inputs in [0, RATE - 1, RATE, 3 * RATE + 1] {
+            let inputs = gen_random_vec(number_of_inputs);
+            let events = Arc::new(AtomicUsize::new(0));
+            tracing::subscriber::with_default(CountingSubscriber(events.clone()), || {
+                poseidon.update(&inputs);
+                poseidon.squeeze_and_reset();
+            });
+            // One permutation per full chunk and one for the padded last chunk
+            assert_eq!(events.load(Ordering::SeqCst), number_of_inputs / RATE + 1);
+        }
+    }
+
+    #[test]
+    fn poseidon_named_constructors() {
+        let inputs = gen_random_vec(5);
+
+        let mut poseidon = Poseidon::<Fr, _, _>::t3_rate2();
+        poseidon.update(&inputs);
+        let mut expected = Poseidon::<Fr, 3, 2>::new(8, 57);
+        expected.update(&inputs);
+        assert_eq!(poseidon.squeeze(), expected.squeeze());
+
+        let mut poseidon = Poseidon::<Fr, _, _>::t5_rate4();
+        poseidon.update(&inputs);
+        let mut expected = Poseidon::<Fr, 5, 4>::new(8, 60);
+        expected.update(&inputs);
+        assert_eq!(poseidon.squeeze(), expected.squeeze());
+
+        // Spec of width 3 matches the reference test vector
+        let spec = Poseidon::<Fr, _, _>::t3_rate2().spec;
+        let mut state = State([Fr::from(0), Fr::from(1), Fr::from(2)]);
+        spec.permute(&mut state);
+        assert_eq!(
+            state.result(),
+            Fr::from_str_vartime(
+                "7142104613055408817911962100316808866448378443474503659992478482890339429929"
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn poseidon_new_with_uniform_bytes() {
+        // BN254 scalar field only implements 64 byte uniform sampling, so this
+        // covers the generic path against the default constructor
+        let inputs = gen_random_vec(RATE + 1);
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new_with_uniform_bytes::<64>(R_F, R_P);
+        let mut expected = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        assert!(poseidon.same_config(&expected));
+        poseidon.update(&inputs);
+        expected.update(&inputs);
+        assert_eq!(poseidon.squeeze(), expected.squeeze());
+    }
+
+    #[test]
+    fn poseidon_hash_chain() {
+        let hash = |value: Fr| {
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            poseidon.update(&[value]);
+            poseidon.squeeze()
+        };
+        let seed = Fr::random(OsRng);
+
+        let chain = Poseidon::<Fr, T, RATE>::hash_chain(seed, 3, R_F, R_P);
+        assert_eq!(chain, hash(hash(hash(seed))));
+        assert_eq!(Poseidon::<Fr, T, RATE>::hash_chain(seed, 0, R_F, R_P), seed);
+
+        let (checkpoints, end) =
+            Poseidon::<Fr, T, RATE>::hash_chain_with_checkpoints(seed, 7, 2, R_F, R_P);
+        assert_eq!(end, Poseidon::<Fr, T, RATE>::hash_chain(seed, 7, R_F, R_P));
+        assert_eq!(checkpoints.len(), 3);
+        let mut value = seed;
+        for checkpoint in checkpoints {
+            value = hash(hash(value));
+            assert_eq!(checkpoint, value);
+        }
+        assert_eq!(end, hash(value));
+    }
+
+    #[test]
+    #[cfg(feature = "bincode")]
+    fn poseidon_hash_serializable() {
+        #[derive(Clone)]
+        struct Record {
+            id: u64,
+            name: String,
+            values: Vec<u8>,
+        }
+
+        impl serde::Serialize for Record {
+            fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                (self.id, &self.name, &self.values).serialize(serializer)
+            }
+        }
+
+        let hash =
+            |record: &Record| Poseidon::<Fr, T, RATE>::hash_serializable(record, R_F, R_P).unwrap();
+        let record = Record {
+            id: 7,
+            name: "poseidon".to_string(),
+            values: (0..100).collect(),
+        };
+        assert_eq!(hash(&record), hash(&record.clone()));
+
+        let bytes = bincode::serialize(&record).unwrap();
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&pack_bytes(&bytes));
+        poseidon.update(&[Fr::from(bytes.len() as u64)]);
+        assert_eq!(hash(&record), poseidon.squeeze());
+
+        let mut changed = record.clone();
+        changed.values[99] = 0;
+        assert_ne!(hash(&record), hash(&changed));
+    }
+
+    #[test]
+    fn poseidon_result_mode() {
+        let inputs = gen_random_vec(RATE + 1);
+        let hash = |result_mode: ResultMode| {
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P).with_result_mode(result_mode);
+            poseidon.update(&inputs);
+            poseidon.squeeze()
+        };
+
+        let single = hash(ResultMode::Single);
+        let sum = hash(ResultMode::Sum);
+        assert_eq!(single, hash(ResultMode::Single));
+        assert_eq!(sum, hash(ResultMode::Sum));
+        assert_ne!(single, sum);
+
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        assert!(!poseidon.same_config(&poseidon.clone().with_result_mode(ResultMode::Sum)));
+        poseidon.update(&inputs);
+        assert_eq!(poseidon.squeeze(), single);
+        let words = poseidon.state.words();
+        assert_eq!(words[1], single);
+        assert_eq!(words[1..].iter().sum::<Fr>(), sum);
+    }
+
+    #[test]
+    fn poseidon_from_spec() {
+        let spec = Spec::<Fr, T, RATE>::new(R_F, R_P);
+        let shared = Spec::from_constants(
+            spec.r_f(),
+            spec.alpha(),
+            spec.mds_matrices().clone(),
+            spec.constants().clone(),
+        )
+        .unwrap();
+        assert_eq!(shared, spec);
+
+        let inputs = gen_random_vec(RATE + 1);
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&inputs);
+        let expected = poseidon.squeeze();
+        for _ in 0..2 {
+            let mut poseidon = Poseidon::from_spec(shared.clone());
+            poseidon.update(&inputs);
+            assert_eq!(poseidon.squeeze(), expected);
+        }
+    }
+
+    #[test]
+    fn poseidon_hash_metered() {
+        assert_eq!(Poseidon::<Fr, T, RATE>::permutation_count(0), 1);
+        assert_eq!(Poseidon::<Fr, T, RATE>::permutation_count(RATE - 1), 1);
+        assert_eq!(Poseidon::<Fr, T, RATE>::permutation_count(RATE), 2);
+
+        let inputs = gen_random_vec(2 * RATE + 1);
+        let mut expected = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        expected.update(&inputs);
+        assert_eq!(
+            Poseidon::<Fr, T, RATE>::hash_metered(&inputs, 3, R_F, R_P),
+            Ok(expected.squeeze())
+        );
+
+        // Rejected before the spec is even constructed, so invalid rounds are
+        // never reached
+        assert_eq!(
+            Poseidon::<Fr, T, RATE>::hash_metered(&inputs, 2, 0, R_P),
+            Err(Error::PermutationBudgetExceeded {
+                required: 3,
+                budget: 2
+            })
+        );
+    }
+
+    #[test]
+    fn poseidon_ring_challenge() {
+        let (ring, message, commitments) =
+            (gen_random_vec(5), Fr::random(OsRng), gen_random_vec(5));
+        let challenge = |ring: &[Fr], commitments: &[Fr]| {
+            Poseidon::<Fr, T, RATE>::ring_challenge(ring, message, commitments, R_F, R_P)
+        };
+
+        // Prover and verifier
+        assert_eq!(
+            challenge(&ring, &commitments),
+            challenge(&ring, &commitments)
+        );
+
+        let mut reordered = ring.clone();
+        reordered.swap(0, 3);
+        assert_ne!(
+            challenge(&ring, &commitments),
+            challenge(&reordered, &commitments)
+        );
+
+        // Members can't move between the ring and the commitments
+        let joined = [ring.clone(), commitments.clone()].concat();
+        assert_ne!(
+            challenge(&ring, &commitments),
+            challenge(&joined[..6], &joined[6..])
+        );
+    }
+
+    #[test]
+    fn poseidon_new_with_domain() {
+        let inputs = gen_random_vec(RATE + 1);
+        let hash = |mut poseidon: Poseidon<Fr, T, RATE>| {
+            poseidon.update(&inputs);
+            poseidon.squeeze_and_reset()
+        };
+        let (domain_0, domain_1) = (Fr::from(1), Fr::from(2));
+
+        let digest_0 = hash(Poseidon::new_with_domain(R_F, R_P, domain_0));
+        assert_ne!(
+            digest_0,
+            hash(Poseidon::new_with_domain(R_F, R_P, domain_1))
+        );
+        assert_ne!(digest_0, hash(Poseidon::new(R_F, R_P)));
+        assert_eq!(
+            hash(Poseidon::new(R_F, R_P)),
+            hash(Poseidon::new_with_domain(R_F, R_P, Fr::from_u128(1 << 64)))
+        );
+
+        // Domain survives reset
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new_with_domain(R_F, R_P, domain_0);
+        poseidon.update(&gen_random_vec(3));
+        poseidon.reset();
+        assert_eq!(poseidon.state.0[0], domain_0);
+        assert_eq!(hash(poseidon), digest_0);
+    }
+
+    #[test]
+    fn poseidon_checksum() {
+        let inputs = gen_random_vec(RATE + 1);
+        let checksum = |inputs: &[&[Fr]]| {
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P).with_checksum();
+            poseidon.update_many(inputs);
+            poseidon.checksum().unwrap()
+        };
+
+        assert_eq!(checksum(&[&inputs]), checksum(&[&inputs]));
+        assert_eq!(
+            checksum(&[&inputs[..2], &inputs[2..]]),
+            checksum(&[&inputs])
+        );
+        let mut changed = inputs.clone();
+        changed[RATE] += Fr::one();
+        assert_ne!(checksum(&[&changed]), checksum(&[&inputs]));
+
+        // Reset clears the checksum and keeps it enabled
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P).with_checksum();
+        let empty = poseidon.checksum();
+        poseidon.update(&inputs);
+        poseidon.reset();
+        assert_eq!(poseidon.checksum(), empty);
+
+        assert_eq!(Poseidon::<Fr, T, RATE>::new(R_F, R_P).checksum(), None);
+    }
+
+    #[test]
+    fn poseidon_hash_constant_length() {
+        // Computes the constant length hash with explicit permutations
+        fn expected<const N: usize>(inputs: [Fr; N]) -> Fr {
+            let spec = Spec::<Fr, T, RATE>::new(R_F, R_P);
+            let mut state = State::<Fr, T>::constant_length(N);
+            let mut padded = inputs.to_vec();
+            padded.resize(N.div_ceil(RATE).max(1) * RATE, Fr::zero());
+            for chunk in padded.chunks(RATE) {
+                for (word, input) in state.0.iter_mut().skip(1).zip(chunk.iter()) {
+                    *word += input;
+                }
+                spec.permute(&mut state);
+            }
+            state.result()
+        }
+        let inputs = (1..=2 * RATE as u64 + 1).map(Fr::from).collect::<Vec<Fr>>();
+
+        let short: [Fr; RATE - 1] = inputs[..RATE - 1].try_into().unwrap();
+        let full: [Fr; RATE] = inputs[..RATE].try_into().unwrap();
+        let long: [Fr; 2 * RATE + 1] = inputs.clone().try_into().unwrap();
+        let digests = [
+            Poseidon::<Fr, T, RATE>::hash(short, R_F, R_P),
+            Poseidon::<Fr, T, RATE>::hash(full, R_F, R_P),
+            Poseidon::<Fr, T, RATE>::hash(long, R_F, R_P),
+        ];
+        let vectors = [
+            "0x0c29c12202f8a758ae3ae01ecf3195031d0486df0c586b7fc003b480b7319a88",
+            "0x1bbecd76672ef47657d3bf1f69c747ee5f4bf2241dc221244dc0e3a85b3c7325",
+            "0x1b37adba5ef37281299f8e4115cf1a3fc37b822ed4327f68022b4141a4ffa987",
+        ];
+        for (digest, vector) in digests.iter().zip(vectors) {
+            assert_eq!(*digest, from_hex(vector).unwrap());
+        }
+        assert_eq!(digests, [expected(short), expected(full), expected(long)]);
+
+        // Not the variable length hash
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&full);
+        assert_ne!(digests[1], poseidon.squeeze());
+    }
+
+    #[test]
+    fn poseidon_map_key() {
+        use std::collections::HashMap;
+
+        let key = |inputs: &[Fr]| Poseidon::<Fr, T, RATE>::map_key(inputs, R_F, R_P);
+        let inputs = gen_random_vec(RATE + 1);
+        assert_eq!(key(&inputs), key(&inputs.clone()));
+        assert_ne!(key(&inputs), key(&inputs[..RATE]));
+
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&inputs);
+        let digest = poseidon.squeeze().to_repr();
+        assert!(key(&inputs).iter().eq(digest.iter().rev()));
+
+        let mut map = HashMap::new();
+        map.insert(key(&inputs), 0);
+        map.insert(key(&inputs[1..]), 1);
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get(&key(&inputs)), Some(&0));
+    }
+
+    #[test]
+    fn poseidon_hash_with_tag() {
+        let inputs = gen_random_vec(RATE + 2);
+        let (key, wrong_key) = (Fr::random(OsRng), Fr::random(OsRng));
+        let (digest, tag) = Poseidon::<Fr, T, RATE>::hash_with_tag(&inputs, key, R_F, R_P);
+
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&inputs);
+        assert_eq!(digest, poseidon.squeeze());
+
+        let verify =
+            |digest, tag, key| Poseidon::<Fr, T, RATE>::verify_tag(digest, tag, key, R_F, R_P);
+        assert!(verify(digest, tag, key));
+        assert!(!verify(digest, tag, wrong_key));
+        assert!(!verify(digest + Fr::one(), tag, key));
+        assert!(!verify(digest, tag + Fr::one(), key));
+        assert_ne!(
+            tag,
+            Poseidon::<Fr, T, RATE>::hash_with_tag(&inputs, wrong_key, R_F, R_P).1
+        );
+    }
+
+    #[test]
+    fn poseidon_sliding_window_hashes() {
+        let data = gen_random_vec(2 * RATE + 1);
+        let hashes =
+            |window| Poseidon::<Fr, T, RATE>::sliding_window_hashes(&data, window, R_F, R_P);
+
+        let windows = hashes(3);
+        assert_eq!(windows.len(), data.len() - 3 + 1);
+        for (i, hash) in windows.iter().enumerate() {
+            let inputs = [data[i], data[i + 1], data[i + 2]];
+            assert_eq!(
+                *hash,
+                Poseidon::<Fr, T, RATE>::hash_tagged(&inputs, R_F, R_P)
+            );
+        }
+
+        assert_eq!(
+            hashes(data.len()),
+            [Poseidon::<Fr, T, RATE>::hash_tagged(&data, R_F, R_P)]
+        );
+        assert!(hashes(data.len() + 1).is_empty());
+    }
+
+    #[test]
+    fn poseidon_hash_n() {
+        fn check<const N: usize>(spec: &Spec<Fr, T, RATE>) {
+            let inputs: [Fr; N] = core::array::from_fn(|_| Fr::random(OsRng));
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            poseidon.update(&inputs);
+            assert_eq!(Poseidon::hash_n(&inputs, spec), poseidon.squeeze());
+        }
+
+        let spec = Spec::<Fr, T, RATE>::new(R_F, R_P);
+        check::<1>(&spec);
+        check::<2>(&spec);
+        check::<3>(&spec);
+        check::<4>(&spec);
+        check::<5>(&spec);
+        check::<6>(&spec);
+        check::<7>(&spec);
+        check::<8>(&spec);
+    }
+
+    #[test]
+    fn poseidon_hash_reversed() {
+        let (a, b) = (Fr::random(OsRng), Fr::random(OsRng));
+        let hash = |inputs: &[Fr]| {
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            poseidon.update(inputs);
+            poseidon.squeeze()
+        };
+        let reversed = |inputs: &[Fr]| Poseidon::<Fr, T, RATE>::hash_reversed(inputs, R_F, R_P);
+
+        assert_eq!(reversed(&[a, b]), hash(&[b, a]));
+        assert_ne!(reversed(&[a, b]), hash(&[a, b]));
+        assert_eq!(reversed(&[]), hash(&[]));
+
+        let inputs = gen_random_vec(2 * RATE + 1);
+        let mut expected = inputs.clone();
+        expected.reverse();
+        assert_eq!(reversed(&inputs), hash(&expected));
+    }
+
+    #[test]
+    fn poseidon_hash_batch() {
+        let spec = Spec::<Fr, T, RATE>::new(R_F, R_P);
+        let inputs = [0, 1, RATE - 1, RATE, RATE + 1, 2 * RATE, 1]
+            .into_iter()
+            .map(gen_random_vec)
+            .collect::<Vec<Vec<Fr>>>();
+
+        let expected = inputs
+            .iter()
+            .map(|inputs| Poseidon::<Fr, T, RATE>::hash_tagged(inputs, R_F, R_P))
+            .collect::<Vec<Fr>>();
+        assert_eq!(Poseidon::hash_batch(&inputs, &spec), expected);
+        assert!(Poseidon::hash_batch(&[], &spec).is_empty());
+    }
+
+    #[test]
+    fn poseidon_hash_tagged() {
+        let (a, zero) = (Fr::random(OsRng), Fr::zero());
+        let hash = |inputs: &[Fr]| Poseidon::<Fr, T, RATE>::hash_tagged(inputs, R_F, R_P);
+
+        let mut expected = Poseidon::<Fr, T, RATE>::new_fixed_length(2, R_F, R_P);
+        expected.update(&[a, zero]);
+        assert_eq!(hash(&[a, zero]), expected.squeeze());
+
+        let inputs = [
+            vec![],
+            vec![zero],
+            vec![a],
+            vec![a, zero],
+            vec![zero, a],
+            vec![zero; RATE - 1],
+            vec![zero; RATE],
+            vec![zero; RATE + 1],
+            [vec![a], vec![zero; RATE]].concat(),
+        ];
+        let digests = inputs
+            .iter()
+            .map(|inputs| hash(inputs))
+            .collect::<Vec<Fr>>();
+        for (i, digest) in digests.iter().enumerate() {
+            assert!(!digests[i + 1..].contains(digest));
+        }
+    }
+
+    #[test]
+    fn poseidon_hash_salted() {
+        let hash =
+            |salt: Fr, inputs: &[Fr]| Poseidon::<Fr, T, RATE>::hash_salted(salt, inputs, R_F, R_P);
+
+        for number_of_inputs in [0, 1, RATE - 1, RATE, 2 * RATE] {
+            let inputs = gen_random_vec(number_of_inputs);
+            let (salt_0, salt_1) = (Fr::random(OsRng), Fr::random(OsRng));
+            assert_eq!(hash(salt_0, &inputs), hash(salt_0, &inputs));
+            assert_ne!(hash(salt_0, &inputs), hash(salt_1, &inputs));
+
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            poseidon.update(&[salt_0]);
+            poseidon.update(&inputs);
+            assert_eq!(hash(salt_0, &inputs), poseidon.squeeze());
+        }
+    }
+
+    #[test]
+    fn poseidon_hash_str() {
+        let hash = |s: &str| Poseidon::<Fr, T, RATE>::hash_str(s, R_F, R_P);
+
+        let long = "poseidon".repeat(10);
+        let strings = ["", "a", "a\0", "\0a", "ab", "ba", "poséidon", &long];
+        for (i, s_0) in strings.iter().enumerate() {
+            assert_eq!(hash(s_0), hash(s_0));
+            for s_1 in strings.iter().skip(i + 1) {
+                assert_ne!(hash(s_0), hash(s_1));
+            }
+        }
+
+        // 31 bytes fit in a single element
+        let s = "abcdefghijklmnopqrstuvwxyz01234";
+        let mut packed = Fr::zero();
+        for byte in s.bytes().rev() {
+            packed = packed * Fr::from(256) + Fr::from(byte as u64);
+        }
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&[packed, Fr::from(31)]);
+        assert_eq!(hash(s), poseidon.squeeze());
+    }
+
+    #[test]
+    fn poseidon_byte_apis_agree() {
+        for len in [0, 1, 30, 31, 32, 62, 100] {
+            let bytes = (0..len).map(|i| i as u8 + 1).collect::<Vec<u8>>();
+            let s = core::str::from_utf8(&bytes).unwrap();
+            let expected = Poseidon::<Fr, T, RATE>::hash_str(s, R_F, R_P);
+
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            poseidon.update_bytes_le(&bytes);
+            assert_eq!(poseidon.squeeze(), expected);
+
+            #[cfg(feature = "std")]
+            {
+                let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+                assert_eq!(poseidon.hash_reader(&bytes[..]).unwrap(), expected);
+            }
+
+            // Digest of the empty input is the clear hasher output
+            if len > 0 {
+                let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+                let (head, tail) = bytes.split_at(len / 3);
+                Update::update(&mut poseidon, head);
+                Update::update(&mut poseidon, tail);
+                let expected = expected
+                    .to_repr()
+                    .iter()
+                    .rev()
+                    .copied()
+                    .collect::<Vec<u8>>();
+                assert_eq!(poseidon.finalize_fixed().to_vec(), expected);
+            }
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn poseidon_hash_reader_counted() {
+        use std::io::Read;
+
+        for len in [0, 1, 30, 31, 32, 62, 100, 1000] {
+            let bytes = (0..len).map(|i| i as u8).collect::<Vec<u8>>();
+
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            let (result, count) = poseidon.hash_reader_counted(&bytes[..]).unwrap();
+            assert_eq!(count, len as u64);
+
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            assert_eq!(poseidon.hash_reader(&bytes[..]).unwrap(), result);
+
+            // Split reads must not change the packing
+            let (head, tail) = bytes.split_at(len / 3);
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            assert_eq!(poseidon.hash_reader(head.chain(tail)).unwrap(), result);
+
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            poseidon.update(&pack_bytes(&bytes));
+            poseidon.update(&[Fr::from(len as u64)]);
+            assert_eq!(poseidon.squeeze(), result);
+        }
+    }
+
+    #[test]
+    fn poseidon_challenge_and_absorb() {
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&gen_random_vec(RATE + 1));
+
+        let mut expected = poseidon.clone();
+        let mut tampered = poseidon.clone();
+        let mut squeezed = poseidon.clone();
+
+        let challenge = poseidon.challenge_and_absorb();
+        let next = poseidon.squeeze();
+
+        assert_eq!(challenge, expected.squeeze());
+        expected.update(&[challenge]);
+        assert_eq!(next, expected.squeeze());
+
+        tampered.squeeze();
+        tampered.update(&[challenge + Fr::one()]);
+        assert_ne!(next, tampered.squeeze());
+
+        squeezed.squeeze();
+        assert_ne!(next, squeezed.squeeze());
+    }
+
+    #[test]
+    fn poseidon_finalize_guard() {
+        // Digest output is the big endian representation of the result
+        let to_bytes = |e: Fr| e.to_repr().iter().rev().copied().collect::<Vec<u8>>();
+
+        let inputs = gen_random_vec(RATE + 1);
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&inputs);
+        let mut expected = to_bytes(poseidon.clone().squeeze());
+        assert_eq!(poseidon.clone().finalize_fixed().to_vec(), expected);
+
+        // Absorbing after a squeeze starts a new message
+        poseidon.squeeze();
+        poseidon.update(&inputs);
+        expected = to_bytes(poseidon.clone().squeeze());
+        assert_eq!(poseidon.finalize_fixed().to_vec(), expected);
+    }
+
+    /// Field with the arithmetic of `Fr` and a 48 byte little endian
+    /// representation whose high bytes are zero, for exercising output sizes
+    /// other than 32 bytes and sampling from 32 uniform bytes
+    mod fr48 {
+        use crate::bytes::ReprBytes;
+        use core::iter::{Product, Sum};
+        use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+        use halo2curves_axiom::bn256::Fr;
+        use halo2curves_axiom::group::ff::{Field, FromUniformBytes, PrimeField};
+        use rand_core::RngCore;
+        use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+        pub(super) struct Fr48(pub(super) Fr);
+
+        #[derive(Clone, Copy)]
+        pub(super) struct Repr48([u8; 48]);
+
+        impl Default for Repr48 {
+            fn default() -> Self {
+                Repr48([0; 48])
+            }
+        }
+
+        impl AsRef<[u8]> for Repr48 {
+            fn as_ref(&self) -> &[u8] {
+                &self.0
+            }
+        }
+
+        impl AsMut<[u8]> for Repr48 {
+            fn as_mut(&mut self) -> &mut [u8] {
+                &mut self.0
+            }
+        }
+
+        macro_rules! impl_binop {
+            ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident) => {
+                impl $trait for Fr48 {
+                    type Output = Fr48;
+                    fn $method(self, rhs: Fr48) -> Fr48 {
+                        Fr48(self.0.$method(rhs.0))
+                    }
+                }
+                impl<'a> $trait<&'a Fr48> for Fr48 {
+                    type Output = Fr48;
+                    fn $method(self, rhs: &'a Fr48) -> Fr48 {
+                        Fr48(self.0.$method(rhs.0))
+                    }
+                }
+                impl $assign_trait for Fr48 {
+                    fn $assign_method(&mut self, rhs: Fr48) {
+                        self.0.$assign_method(rhs.0)
+                    }
+                }
+                impl<'a> $assign_trait<&'a Fr48> for Fr48 {
+                    fn $assign_method(&mut self, rhs: &'a Fr48) {
+                        self.0.$assign_method(rhs.0)
+                    }
+                }
+            };
+        }
+
+        impl_binop!(Add, add, AddAssign, add_assign);
+        impl_binop!(Sub, sub, SubAssign, sub_assign);
+        impl_binop!(Mul, mul, MulAssign, mul_assign);
+
+        impl Neg for Fr48 {
+            type Output = Fr48;
+            fn neg(self) -> Fr48 {
+                Fr48(-self.0)
+            }
+        }
+
+        impl Sum for Fr48 {
+            fn sum<I: Iterator<Item = Fr48>>(iter: I) -> Fr48 {
+                iter.fold(Fr48::ZERO, |acc, e| acc + e)
+            }
+        }
+
+        impl<'a> Sum<&'a Fr48> for Fr48 {
+            fn sum<I: Iterator<Item = &'a Fr48>>(iter: I) -> Fr48 {
+                iter.fold(Fr48::ZERO, |acc, e| acc + e)
+            }
+        }
+
+        impl Product for Fr48 {
+            fn product<I: Iterator<Item = Fr48>>(iter: I) -> Fr48 {
+                iter.fold(Fr48::ONE, |acc, e| acc * e)
+            }
+        }
+
+        impl<'a> Product<&'a Fr48> for Fr48 {
+            fn product<I: Iterator<Item = &'a Fr48>>(iter: I) -> Fr48 {
+                iter.fold(Fr48::ONE, |acc, e| acc * e)
+            }
+        }
+
+        impl ConditionallySelectable for Fr48 {
+            fn conditional_select(a: &Fr48, b: &Fr48, choice: Choice) -> Fr48 {
+                Fr48(Fr::conditional_select(&a.0, &b.0, choice))
+            }
+        }
+
+        impl ConstantTimeEq for Fr48 {
+            fn ct_eq(&self, other: &Fr48) -> Choice {
+                self.0.ct_eq(&other.0)
+            }
+        }
+
+        impl From<u64> for Fr48 {
+            fn from(v: u64) -> Fr48 {
+                Fr48(Fr::from(v))
+            }
+        }
+
+        impl Field for Fr48 {
+            const ZERO: Fr48 = Fr48(Fr::ZERO);
+            const ONE: Fr48 = Fr48(Fr::ONE);
+
+            fn random(rng: impl RngCore) -> Fr48 {
+                Fr48(Fr::random(rng))
+            }
+            fn square(&self) -> Fr48 {
+                Fr48(self.0.square())
+            }
+            fn double(&self) -> Fr48 {
+                Fr48(self.0.double())
+            }
+            fn invert(&self) -> CtOption<Fr48> {
+                self.0.invert().map(Fr48)
+            }
+            fn sqrt_ratio(num: &Fr48, div: &Fr48) -> (Choice, Fr48) {
+                let (is_square, root) = Fr::sqrt_ratio(&num.0, &div.0);
+                (is_square, Fr48(root))
+            }
+        }
+
+        impl PrimeField for Fr48 {
+            type Repr = Repr48;
+
+            fn from_repr(repr: Repr48) -> CtOption<Fr48> {
+                let mut inner = <Fr as PrimeField>::Repr::default();
+                inner.as_mut().copy_from_slice(&repr.0[..32]);
+                let high_zero = repr.0[32..]
+                    .iter()
+                    .fold(0, |acc, byte| acc | byte)
+                    .ct_eq(&0);
+                Fr::from_repr(inner)
+                    .map(Fr48)
+                    .and_then(|e| CtOption::new(e, high_zero))
+            }
+            fn to_repr(&self) -> Repr48 {
+                let mut repr = Repr48::default();
+                repr.0[..32].copy_from_slice(self.0.to_repr().as_ref());
+                repr
+            }
+            fn is_odd(&self) -> Choice {
+                self.0.is_odd()
+            }
+
+            const MODULUS: &'static str = Fr::MODULUS;
+            const NUM_BITS: u32 = Fr::NUM_BITS;
+            const CAPACITY: u32 = Fr::CAPACITY;
+            const TWO_INV: Fr48 = Fr48(Fr::TWO_INV);
+            const MULTIPLICATIVE_GENERATOR: Fr48 = Fr48(Fr::MULTIPLICATIVE_GENERATOR);
+            const S: u32 = Fr::S;
+            const ROOT_OF_UNITY: Fr48 = Fr48(Fr::ROOT_OF_UNITY);
+            const ROOT_OF_UNITY_INV: Fr48 = Fr48(Fr::ROOT_OF_UNITY_INV);
+            const DELTA: Fr48 = Fr48(Fr::DELTA);
+        }
+
+        impl FromUniformBytes<64> for Fr48 {
+            fn from_uniform_bytes(bytes: &[u8; 64]) -> Fr48 {
+                Fr48(Fr::from_uniform_bytes(bytes))
+            }
+        }
+
+        impl FromUniformBytes<32> for Fr48 {
+            fn from_uniform_bytes(bytes: &[u8; 32]) -> Fr48 {
+                let mut wide = [0; 64];
+                wide[..32].copy_from_slice(bytes);
+                Fr48(Fr::from_uniform_bytes(&wide))
+            }
+        }
+
+        impl ReprBytes for Repr48 {
+            type Size = typenum::U48;
+        }
+    }
+
+    #[test]
+    fn poseidon_digest_on_48_byte_field() {
+        use digest::Digest;
+        use fr48::Fr48;
+
+        let bytes = (0..100).map(|i| i as u8).collect::<Vec<u8>>();
+        let output = <Poseidon<Fr48, T, RATE> as Digest>::digest(&bytes);
+        assert_eq!(output.len(), 48);
+
+        // Output is the big endian representation of the squeezed element
+        let mut poseidon = Poseidon::<Fr48, T, RATE>::default();
+        Update::update(&mut poseidon, &bytes);
+        poseidon.finish_bytes();
+        let element = poseidon.squeeze();
+        let mut repr = <Fr48 as PrimeField>::Repr::default();
+        for (dst, src) in repr.as_mut().iter_mut().zip(output.iter().rev()) {
+            *dst = *src;
+        }
+        assert_eq!(Fr48::from_repr(repr).unwrap(), element);
+
+        // Same hash as over `Fr` with the representation zero extended
+        let expected = <Poseidon<Fr, T, RATE> as Digest>::digest(&bytes);
+        assert_eq!(element.0.to_repr().as_ref().len(), 32);
+        assert_eq!(output[..16], [0; 16]);
+        assert_eq!(output[16..], expected[..]);
+
+        let chained = <Poseidon<Fr48, T, RATE> as Digest>::new()
+            .chain_update(&bytes[..33])
+            .chain_update(&bytes[33..])
+            .finalize();
+        assert_eq!(chained, output);
+    }
+
+    #[test]
+    fn poseidon_digest_on_pasta_field() {
+        use digest::Digest;
+        use halo2curves_axiom::pasta::Fp;
+
+        let bytes = (0..100).map(|i| i as u8).collect::<Vec<u8>>();
+        let output = <Poseidon<Fp, T, RATE> as Digest>::digest(&bytes);
+        assert_eq!(output.len(), 32);
+
+        // Packed bytes followed by the byte length, output is big endian
+        let mut poseidon = Poseidon::<Fp, T, RATE>::default();
+        poseidon.update(&pack_bytes::<Fp>(&bytes));
+        poseidon.update(&[Fp::from(bytes.len() as u64)]);
+        let expected = poseidon.squeeze().to_repr();
+        assert!(output.iter().eq(expected.iter().rev()));
+    }
+
+    #[test]
+    fn poseidon_new_with_32_uniform_bytes() {
+        use fr48::Fr48;
+
+        // Grain output fits into 32 bytes, so constants are the same as the
+        // ones sampled from 64 bytes
+        let inputs = gen_random_vec(RATE + 1);
+        let mut poseidon = Poseidon::<Fr48, T, RATE>::new_with_uniform_bytes::<32>(R_F, R_P);
+        let mut expected = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&inputs.iter().copied().map(Fr48).collect::<Vec<Fr48>>());
+        expected.update(&inputs);
+        assert_eq!(poseidon.squeeze().0, expected.squeeze());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "already finalized")]
+    fn poseidon_finalize_guard_squeezed() {
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&gen_random_vec(RATE + 1));
+        poseidon.squeeze();
+        poseidon.clone().finalize_fixed();
+    }
+
+    #[test]
+    fn poseidon_update_bytes() {
+        let finalize = |chunks: &[&[u8]]| {
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            for chunk in chunks.iter() {
+                Update::update(&mut poseidon, chunk);
+            }
+            poseidon.finalize_fixed().to_vec()
+        };
+        let to_bytes = |e: Fr| e.to_repr().iter().rev().copied().collect::<Vec<u8>>();
+
+        for len in [0usize, 1, 30, 31, 32, 4 * 31, 200] {
+            let bytes = (0..len).map(|i| i as u8).collect::<Vec<u8>>();
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            if len > 0 {
+                poseidon.update(&pack_bytes(&bytes));
+                poseidon.update(&[Fr::from(len as u64)]);
+            }
+            let expected = to_bytes(poseidon.squeeze());
+            assert_eq!(finalize(&[&bytes]), expected);
+
+            // Split at arbitrary boundaries
+            for split in [0, len.min(1), len / 2, len.saturating_sub(1), len] {
+                let (left, right) = bytes.split_at(split);
+                assert_eq!(finalize(&[left, &[], right]), expected);
+            }
+            let single_bytes = std::iter::once(&[][..])
+                .chain(bytes.chunks(1))
+                .collect::<Vec<&[u8]>>();
+            assert_eq!(finalize(&single_bytes), expected);
+        }
+
+        // Empty updates are no-ops
+        assert_eq!(finalize(&[&[]]), finalize(&[]));
+        assert_eq!(finalize(&[&[], &[]]), finalize(&[]));
+        assert_ne!(finalize(&[&[0]]), finalize(&[&[]]));
+        assert_ne!(finalize(&[&[0]]), finalize(&[&[0, 0]]));
+    }
+
+    #[test]
+    fn poseidon_squeeze_scalar() {
+        use halo2curves_axiom::bn256::Fq;
+
+        let inputs = gen_random_vec(RATE + 1);
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&inputs);
+
+        let scalar: Fq = poseidon.clone().squeeze_scalar();
+        assert_eq!(scalar, poseidon.clone().squeeze_scalar());
+        assert_eq!(Fq::from_repr(scalar.to_repr()).unwrap(), scalar);
+
+        // Reduction of lo + p * hi into the scalar field
+        let elements = poseidon.squeeze_n(2);
+        let lift = |e: Fr| Fq::from_repr(e.to_repr()).unwrap();
+        let modulus = lift(-Fr::one()) + Fq::one();
+        assert_eq!(scalar, lift(elements[0]) + modulus * lift(elements[1]));
+    }
+
+    #[test]
+    fn poseidon_shuffle_seed() {
+        use rand_chacha::rand_core::{RngCore, SeedableRng};
+        use rand_chacha::ChaCha20Rng;
+
+        let seed = |inputs: &[Fr]| {
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            poseidon.update(inputs);
+            poseidon.shuffle_seed()
+        };
+        let inputs = [Fr::from(1), Fr::from(2), Fr::from(3)];
+        let expected: Fr =
+            from_hex("0x302e350a959fa77ec1c28e65fda6e393c20b3bf83e3e76416a05269f605de69e").unwrap();
+        assert_eq!(seed(&inputs), expected.to_repr());
+
+        let sequence = |seed: [u8; 32]| {
+            let mut rng = ChaCha20Rng::from_seed(seed);
+            (0..8).map(|_| rng.next_u64()).collect::<Vec<u64>>()
+        };
+        assert_eq!(sequence(seed(&inputs)), sequence(seed(&inputs)));
+        assert_ne!(sequence(seed(&inputs)), sequence(seed(&inputs[..2])));
+    }
+
+    #[test]
+    fn poseidon_squeeze_debug() {
+        let inputs = gen_random_vec(RATE + 1);
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&inputs);
+
+        let (output, capacity) = poseidon.clone().squeeze_debug();
+        assert_eq!(output, poseidon.clone().squeeze());
+
+        let state = absorb_padded(&inputs, &poseidon.spec);
+        assert_eq!((output, capacity), (state.result(), state.0[0]));
+
+        // Reference also pads inputs filling the last chunk
+        for len in [0, RATE, 2 * RATE] {
+            let inputs = gen_random_vec(len);
+            let mut hasher = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            hasher.update(&inputs);
+            let state = absorb_padded(&inputs, &hasher.spec);
+            assert_eq!(hasher.squeeze_debug(), (state.result(), state.0[0]));
+        }
+
+        // Further calls permute the state again like squeeze
+        let mut debug = poseidon.clone();
+        debug.squeeze_debug();
+        poseidon.squeeze();
+        assert_eq!(debug.squeeze_debug().0, poseidon.squeeze());
+    }
+
+    #[test]
+    fn poseidon_squeeze_bytes_31() {
+        let inputs = gen_random_vec(RATE + 1);
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&inputs);
+
+        let bytes = poseidon.clone().squeeze_bytes_31();
+        assert_eq!(bytes, poseidon.clone().squeeze_bytes_31());
+
+        let repr = poseidon.squeeze().to_repr();
+        let mut expected = repr[..31].to_vec();
+        expected.reverse();
+        assert_eq!(bytes.to_vec(), expected);
+
+        // Fits into the field
+        let mut repr = [0u8; 32];
+        for (byte, truncated) in repr.iter_mut().zip(bytes.iter().rev()) {
+            *byte = *truncated;
+        }
+        assert!(bool::from(Fr::from_repr(repr).is_some()));
+    }
+
+    #[test]
+    fn poseidon_flush() {
+        let (segment_0, segment_1) = (gen_random_vec(RATE + 2), gen_random_vec(3));
+        let hash = |flush: bool| {
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            poseidon.update(&segment_0);
+            if flush {
+                poseidon.flush();
+            }
+            poseidon.update(&segment_1);
+            poseidon.squeeze()
+        };
+        assert_eq!(hash(true), hash(true));
+        assert_ne!(hash(true), hash(false));
+
+        // Equivalent to zero padding up to the end of the chunk
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&segment_0);
+        poseidon.update(&vec![Fr::zero(); RATE - 2]);
+        poseidon.update(&segment_1);
+        assert_eq!(hash(true), poseidon.squeeze());
+
+        // Empty line is flushed with a full chunk of zeros
+        let mut flushed = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        flushed.flush();
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&vec![Fr::zero(); RATE]);
+        assert_eq!(flushed.squeeze(), poseidon.squeeze());
+    }
+
+    #[test]
+    fn poseidon_update_many() {
+        let (a, b, c) = (
+            gen_random_vec(RATE + 1),
+            gen_random_vec(2),
+            gen_random_vec(0),
+        );
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update_many(&[&a, &b, &c]);
+
+        let mut expected = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        expected.update(&[a, b, c].concat());
+        assert_eq!(poseidon.absorbed, expected.absorbed);
+        assert_eq!(poseidon.squeeze(), expected.squeeze());
+    }
+
+    #[test]
+    fn poseidon_challenge_vector() {
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&gen_random_vec(RATE + 1));
+        let mut expected = poseidon.clone();
+
+        let challenges = poseidon.clone().challenge_vector(2 * RATE);
+        assert_eq!(challenges, poseidon.challenge_vector(2 * RATE));
+        for (i, challenge) in challenges.iter().enumerate() {
+            assert!(!challenges[i + 1..].contains(challenge));
+            assert_eq!(*challenge, expected.challenge_and_absorb());
+        }
+
+        // Remains absorbable
+        let inputs = gen_random_vec(2);
+        poseidon.update(&inputs);
+        expected.update(&inputs);
+        assert_eq!(poseidon.squeeze(), expected.squeeze());
+        assert!(poseidon.challenge_vector(0).is_empty());
+    }
+
+    #[test]
+    fn poseidon_squeeze_n() {
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&gen_random_vec(RATE + 1));
+        assert_eq!(
+            poseidon.clone().squeeze_n(1),
+            vec![poseidon.clone().squeeze()]
+        );
+
+        // Finishing permutation is applied once even if nothing is read
+        let mut expected = poseidon.clone();
+        assert!(expected.squeeze_n(0).is_empty());
+        let mut outputs = expected.state.0[1..].to_vec();
+        expected.spec.permute(&mut expected.state);
+        outputs.extend_from_slice(&expected.state.0[1..3]);
+        assert_eq!(poseidon.squeeze_n(RATE + 2), outputs);
+
+        // Following calls only permute
+        expected.spec.permute(&mut expected.state);
+        assert_eq!(poseidon.squeeze_n(2), expected.state.0[1..3].to_vec());
+        expected.spec.permute(&mut expected.state);
+        assert_eq!(poseidon.squeeze(), expected.state.result());
+
+        // New inputs apply the padding again
+        let inputs = gen_random_vec(2);
+        poseidon.update(&inputs);
+        expected.update(&inputs);
+        assert_eq!(poseidon.squeeze_n(1), vec![expected.squeeze()]);
+    }
+
+    #[test]
+    #[cfg(feature = "debug-transcript")]
+    fn poseidon_transcript_bytes() {
+        let inputs = gen_random_vec(RATE + 1);
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        assert!(poseidon.transcript_bytes().is_empty());
+
+        poseidon.update(&inputs[..2]);
+        poseidon.update(&inputs[2..]);
+        let challenge = poseidon.challenge_and_absorb();
+        let outputs = poseidon.squeeze_n(2);
+        let public = poseidon.public_inputs(2);
+
+        let mut expected = vec![];
+        let mut entry = |marker: u8, element: &Fr| {
+            expected.push(marker);
+            expected.extend_from_slice(&element.to_repr());
+        };
+        inputs.iter().for_each(|input| entry(0, input));
+        entry(1, &challenge);
+        entry(0, &challenge);
+        outputs.iter().for_each(|output| entry(1, output));
+        public.iter().for_each(|output| entry(1, output));
+
+        let log = poseidon.transcript_bytes();
+        assert_eq!(log.len(), (inputs.len() + 6) * 33);
+        assert_eq!(log, expected);
+
+        poseidon.reset();
+        assert!(poseidon.transcript_bytes().is_empty());
+    }
+
+    #[test]
+    fn poseidon_hash_until() {
+        let inputs = gen_random_vec(RATE);
+        let counter_hash = |counter: u64| {
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            poseidon.update(&inputs);
+            poseidon.update(&[Fr::from(counter)]);
+            poseidon.squeeze()
+        };
+
+        // About half of the outputs have an even representation
+        let is_even = |output: Fr| output.to_repr()[0] & 1 == 0;
+        let output = Poseidon::<Fr, T, RATE>::hash_until(&inputs, is_even, 64, R_F, R_P).unwrap();
+        assert!(is_even(output));
+        let first = (0..64).map(counter_hash).find(|output| is_even(*output));
+        assert_eq!(Some(output), first);
+        assert_eq!(
+            Poseidon::<Fr, T, RATE>::hash_until(&inputs, is_even, 64, R_F, R_P),
+            Some(output)
+        );
+
+        // Accepting everything returns the first counter
+        assert_eq!(
+            Poseidon::<Fr, T, RATE>::hash_until(&inputs, |_| true, 1, R_F, R_P),
+            Some(counter_hash(0))
+        );
+
+        assert_eq!(
+            Poseidon::<Fr, T, RATE>::hash_until(
+                &inputs,
+                |output| output == Fr::zero(),
+                16,
+                R_F,
+                R_P
+            ),
+            None
+        );
+        assert_eq!(
+            Poseidon::<Fr, T, RATE>::hash_until(&inputs, |_| true, 0, R_F, R_P),
+            None
+        );
+    }
+
+    #[test]
+    fn poseidon_squeeze_rate() {
+        let inputs = gen_random_vec(RATE + 1);
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P).with_squeeze_rate(2);
+        poseidon.update(&inputs);
+
+        // Manual reference absorbing at `RATE` and reading 2 elements per
+        // permutation
+        let mut state = absorb_padded(&inputs, &poseidon.spec);
+        let mut expected = vec![];
+        while expected.len() < 5 {
+            if !expected.is_empty() {
+                poseidon.spec.permute(&mut state);
+            }
+            expected.extend_from_slice(&state.0[1..3]);
+        }
+        expected.truncate(5);
+
+        // First output is the same as with the default squeeze rate
+        let mut default = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        default.update(&inputs);
+        assert_eq!(poseidon.clone().squeeze(), default.squeeze());
+        assert_ne!(poseidon.clone().squeeze_n(5), default.squeeze_n(5));
+
+        assert_eq!(poseidon.squeeze_n(5), expected);
+        assert!(!poseidon.same_config(&poseidon.clone().with_squeeze_rate(RATE)));
+    }
+
+    #[test]
+    fn poseidon_public_inputs() {
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&[Fr::from(1), Fr::from(2), Fr::from(3)]);
+        let mut expected = poseidon.clone();
+
+        let outputs = poseidon.clone().public_inputs(3);
+        assert_eq!(
+            outputs[..2],
+            [
+                from_hex("0x302e350a959fa77ec1c28e65fda6e393c20b3bf83e3e76416a05269f605de69e")
+                    .unwrap(),
+                from_hex("0x1dc0aa6cbd2190babf6de25863560280b3c1aa6ce1f403fbb722c8df0d4e25ed")
+                    .unwrap(),
+            ]
+        );
+        assert_eq!(outputs[0], expected.squeeze());
+        expected.spec.permute(&mut expected.state);
+        assert_eq!(outputs[1], expected.state.result());
+        assert_eq!(outputs[..2], poseidon.public_inputs(2)[..]);
+        assert!(Poseidon::<Fr, T, RATE>::new(R_F, R_P)
+            .public_inputs(0)
+            .is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "rng")]
+    fn poseidon_absorb_blinding() {
+        use rand_chacha::rand_core::SeedableRng;
+        use rand_chacha::ChaCha20Rng;
+
+        let data = gen_random_vec(RATE + 1);
+        let challenge = |seed: u64| {
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            poseidon.update(&data);
+            let blinding = poseidon.absorb_blinding(ChaCha20Rng::seed_from_u64(seed));
+            (blinding, poseidon.squeeze())
+        };
+        let (blinding_0, challenge_0) = challenge(0);
+        let (blinding_1, challenge_1) = challenge(1);
+        assert_ne!(blinding_0, blinding_1);
+        assert_ne!(challenge_0, challenge_1);
+        assert_eq!(challenge(0), (blinding_0, challenge_0));
+
+        // Verifier absorbs the received blinding
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&data);
+        poseidon.update(&[blinding_0]);
+        assert_eq!(poseidon.squeeze(), challenge_0);
+    }
+
+    #[test]
+    fn poseidon_begin_message() {
+        let message = gen_random_vec(RATE + 1);
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&gen_random_vec(2));
+        poseidon.begin_message(7, message.len() as u64);
+        poseidon.update(&message);
+        let digest = poseidon.try_squeeze().unwrap();
+
+        let mut expected = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        expected.update(&[Fr::from(7), Fr::from(message.len() as u64)]);
+        expected.update(&message);
+        assert_eq!(digest, expected.squeeze());
+
+        // Squeezing ends the message
+        poseidon.update(&message);
+        assert!(poseidon.try_squeeze().is_ok());
+
+        for actual in [0, message.len() - 1, message.len() + 1] {
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            poseidon.begin_message(7, message.len() as u64);
+            poseidon.update(&gen_random_vec(actual));
+            assert_eq!(
+                poseidon.try_squeeze(),
+                Err(Error::InvalidInputLength {
+                    expected: message.len(),
+                    actual
+                })
+            );
+        }
+
+        // Number of absorbed elements decides whether squeezing succeeds
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.begin_message(7, 2);
+        poseidon.update(&gen_random_vec(2));
+        let mut other = poseidon.clone();
+        other.absorbed += 1;
+        assert!(!poseidon.same_config(&other));
+        assert!(poseidon.try_squeeze().is_ok());
+        assert!(other.try_squeeze().is_err());
+    }
+
+    #[test]
+    fn poseidon_absorb_public_and_private() {
+        let (public, private) = (gen_random_vec(3), gen_random_vec(2));
+        let hash = |public: &[Fr], private: &[Fr]| {
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            poseidon.absorb_public(public);
+            poseidon.absorb_private(private);
+            poseidon.squeeze()
+        };
+        let digest = hash(&public, &private);
+        assert_eq!(digest, hash(&public, &private));
+
+        // Moving an element to the other category
+        let moved = [private.clone(), vec![public[2]]].concat();
+        assert_ne!(digest, hash(&public[..2], &moved));
+        let mut swapped = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        swapped.absorb_private(&public);
+        swapped.absorb_public(&private);
+        assert_ne!(digest, swapped.squeeze());
+
+        let mut expected = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        expected.update(&[Fr::from(0x7075626c6963), Fr::from(3)]);
+        expected.update(&public);
+        expected.update(&[Fr::from(0x70726976617465), Fr::from(2)]);
+        expected.update(&private);
+        assert_eq!(digest, expected.squeeze());
+    }
+
+    #[test]
+    fn poseidon_bind_context() {
+        let data = gen_random_vec(RATE + 1);
+        let challenge = |context: &[Fr]| {
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            poseidon.bind_context(context).unwrap();
+            poseidon.update(&data);
+            poseidon.squeeze()
+        };
+        let (block, chain) = (Fr::from(1000), Fr::from(1));
+        assert_eq!(challenge(&[block, chain]), challenge(&[block, chain]));
+        assert_ne!(
+            challenge(&[block, chain]),
+            challenge(&[block + Fr::one(), chain])
+        );
+        assert_ne!(challenge(&[block, chain]), challenge(&[block]));
+
+        let mut expected = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        expected.update(&[Fr::from(0x636f6e74657874), Fr::from(2), block, chain]);
+        expected.update(&data);
+        assert_eq!(challenge(&[block, chain]), expected.squeeze());
+
+        // Context after protocol data
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&data);
+        assert_eq!(
+            poseidon.bind_context(&[block]),
+            Err(Error::ContextNotFirst {
+                absorbed: data.len()
+            })
+        );
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.squeeze();
+        assert!(poseidon.bind_context(&[block]).is_err());
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.bind_context(&[block]).unwrap();
+        assert!(poseidon.bind_context(&[chain]).is_err());
+
+        // Binding is allowed again after reset
+        poseidon.reset();
+        poseidon.bind_context(&[block, chain]).unwrap();
+        poseidon.update(&data);
+        assert_eq!(poseidon.squeeze(), challenge(&[block, chain]));
+    }
+
+    #[test]
+    fn poseidon_update_bytes_endianness() {
+        let bytes = (0..40).map(|i| i as u8 + 1).collect::<Vec<u8>>();
+        let hash = |update: fn(&mut Poseidon<Fr, T, RATE>, &[u8]), bytes: &[u8]| {
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            update(&mut poseidon, bytes);
+            poseidon.squeeze()
+        };
+        let (le, be) = (Poseidon::update_bytes_le, Poseidon::update_bytes_be);
+        assert_eq!(hash(le, &bytes), hash(le, &bytes));
+        assert_eq!(hash(be, &bytes), hash(be, &bytes));
+        assert_ne!(hash(le, &bytes), hash(be, &bytes));
+        assert_eq!(
+            hash(le, &bytes),
+            Poseidon::<Fr, T, RATE>::hash_str(core::str::from_utf8(&bytes).unwrap(), R_F, R_P)
+        );
+
+        // Reversing each chunk swaps the interpretation
+        let reversed = bytes
+            .chunks(31)
+            .flat_map(|chunk| chunk.iter().rev().copied())
+            .collect::<Vec<u8>>();
+        assert_eq!(hash(le, &bytes), hash(be, &reversed));
+
+        let mut expected = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        let chunk = |bytes: &[u8]| {
+            let mut repr = [0u8; 32];
+            repr[..bytes.len()].copy_from_slice(bytes);
+            Fr::from_repr(repr).unwrap()
+        };
+        expected.update(&[chunk(&reversed[..31]), chunk(&reversed[31..]), Fr::from(40)]);
+        assert_eq!(hash(be, &bytes), expected.squeeze());
+    }
+
+    #[test]
+    fn poseidon_absorb_external_digest() {
+        let digest: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let hash = |digest: &[u8]| {
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            poseidon.absorb_external_digest(digest);
+            poseidon.squeeze()
+        };
+        assert_eq!(hash(&digest), hash(&digest));
+        assert_ne!(hash(&digest), hash(&digest[..31]));
+
+        // Digest that is also a valid field element representation
+        let element = Fr::random(OsRng);
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&[element]);
+        assert_ne!(hash(&element.to_repr()), poseidon.squeeze());
+
+        // Layout of the absorbed elements
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        let mut last = [0u8; 32];
+        last[0] = 31;
+        poseidon.update(&[
+            Fr::from(0x646967657374),
+            Fr::from(32),
+            Fr::from_repr(core::array::from_fn(|i| if i < 31 { i as u8 } else { 0 })).unwrap(),
+            Fr::from_repr(last).unwrap(),
+        ]);
+        assert_eq!(hash(&digest), poseidon.squeeze());
+    }
+
+    #[test]
+    fn poseidon_state_fingerprint() {
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&gen_random_vec(RATE + 1));
+        let fingerprint = poseidon.state_fingerprint();
+        assert_eq!(fingerprint, poseidon.clone().state_fingerprint());
+
+        let mut expected = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        expected.update(&poseidon.state.words());
+        assert_eq!(fingerprint, expected.squeeze());
+
+        for i in 0..T {
+            let mut changed = poseidon.clone();
+            changed.state.0[i] += Fr::one();
+            assert_ne!(fingerprint, changed.state_fingerprint());
+        }
+    }
+
+    #[test]
+    fn poseidon_absorb_domained() {
+        let (domain_0, domain_1) = (Fr::from(1), Fr::from(2));
+        let (payload_0, payload_1) = (gen_random_vec(RATE + 1), gen_random_vec(2));
+        let challenge = |items: &[(Fr, &[Fr])]| {
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            poseidon.absorb_domained(items);
+            poseidon.squeeze()
+        };
+
+        let items = [(domain_0, &payload_0[..]), (domain_1, &payload_1[..])];
+        let reordered = [(domain_1, &payload_1[..]), (domain_0, &payload_0[..])];
+        assert_eq!(challenge(&items), challenge(&items));
+        assert_ne!(challenge(&items), challenge(&reordered));
+
+        // Payload can't be moved over to the next item
+        let shifted = [(domain_0, &payload_0[..RATE]), (domain_1, &payload_1[..])];
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.absorb_domained(&shifted);
+        assert_ne!(challenge(&items), poseidon.squeeze());
+
+        // Layout of the absorbed elements
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&[domain_0, Fr::from(payload_0.len() as u64)]);
+        poseidon.update(&payload_0);
+        poseidon.update(&[domain_1, Fr::from(2)]);
+        poseidon.update(&payload_1);
+        assert_eq!(challenge(&items), poseidon.squeeze());
+        assert_eq!(
+            challenge(&[]),
+            Poseidon::<Fr, T, RATE>::new(R_F, R_P).squeeze()
+        );
+    }
+
+    #[test]
+    fn poseidon_absorb_inclusion() {
+        let (root, leaf) = (Fr::random(OsRng), Fr::random(OsRng));
+        let path = gen_random_vec(4)
+            .into_iter()
+            .zip([true, false, false, true])
+            .collect::<Vec<(Fr, bool)>>();
+        let challenge = |root: Fr, leaf: Fr, path: &[(Fr, bool)]| {
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            poseidon.absorb_inclusion(root, leaf, path);
+            poseidon.squeeze()
+        };
+
+        // Prover and verifier
+        assert_eq!(challenge(root, leaf, &path), challenge(root, leaf, &path));
+
+        assert_ne!(challenge(root, leaf, &path), challenge(leaf, root, &path));
+        assert_ne!(
+            challenge(root, leaf, &path),
+            challenge(root, leaf, &path[..3])
+        );
+        let mut flipped = path.clone();
+        flipped[2].1 = true;
+        assert_ne!(
+            challenge(root, leaf, &path),
+            challenge(root, leaf, &flipped)
+        );
+
+        // Layout of the absorbed elements
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        poseidon.update(&[root, leaf, Fr::from(4)]);
+        for (sibling, direction) in path.iter() {
+            poseidon.update(&[*sibling, Fr::from(*direction as u64)]);
+        }
+        assert_eq!(challenge(root, leaf, &path), poseidon.squeeze());
+    }
+
+    macro_rules! test_padding {
+        ($T:expr, $RATE:expr) => {
+            paste! {
+                #[test]
+                #[allow(clippy::modulo_one)]
+                fn [<test_padding_ $T _ $RATE>]() {
+                    for number_of_iters in 1..25 {
+                        let mut poseidon = Poseidon::<Fr, $T, $RATE>::new(R_F, R_P);
+
+                        let mut inputs = vec![];
+                        for number_of_inputs in 0..=number_of_iters {
+                            let chunk = (0..number_of_inputs)
+                                .map(|_| Fr::random(OsRng))
+                                .collect::<Vec<Fr>>();
+                            poseidon.update(&chunk[..]);
+                            inputs.extend(chunk);
+                        }
+                        let result_0 = poseidon.squeeze();
+
+                        // Accept below as reference and check consistency
+                        inputs.push(Fr::one());
+                        let offset = inputs.len() % $RATE;
+                        if offset != 0 {
+                            inputs.extend(vec![Fr::zero(); $RATE - offset]);
+                        }
+
+                        let spec = poseidon.spec.clone();
+                        let mut state = State::<Fr, $T>::default();
+                        for chunk in inputs.chunks($RATE) {
+                            // First element is zero
+                            let mut round_inputs = vec![Fr::zero()];
+                            // Round inputs must be T sized now
+                            round_inputs.extend_from_slice(chunk);
+
+                            state.add_constants(&round_inputs.try_into().unwrap());
+                            spec.permute(&mut state)
+                        }
+                        let result_1 = state.result();
+                        assert_eq!(result_0, result_1);
+                    }
+                }
+            }
+        };
+    }
+
+    test_padding!(2, 1);
+    test_padding!(3, 2);
+    test_padding!(4, 3);
+    test_padding!(5, 4);
     test_padding!(6, 5);
     test_padding!(7, 6);
     test_padding!(8, 7);
     test_padding!(9, 8);
     test_padding!(10, 9);
+
+    macro_rules! test_stable_digests {
+        ($T:expr, $RATE:expr, $EMPTY:expr, $SINGLE:expr) => {
+            paste! {
+                #[test]
+                fn [<test_stable_digests_ $T _ $RATE>]() {
+                    let hash = |inputs: &[Fr]| {
+                        let mut poseidon = Poseidon::<Fr, $T, $RATE>::new(R_F, R_P);
+                        poseidon.update(inputs);
+                        poseidon.squeeze()
+                    };
+                    assert_eq!(hash(&[]), Fr::from_str_vartime($EMPTY).unwrap());
+                    assert_eq!(hash(&[Fr::one()]), Fr::from_str_vartime($SINGLE).unwrap());
+                }
+            }
+        };
+    }
+
+    // Digests of the empty input and of the single element one with R_F = 8
+    // and R_P = 57
+    test_stable_digests!(
+        2,
+        1,
+        "13610816013651282090475823066872132600378401320575374174833557169407429637316",
+        "8462418260615162906149614732769244162883624804043970406579468138578865809135"
+    );
+    test_stable_digests!(
+        3,
+        2,
+        "9362338198138338175086986366978400070207062939342763846442323463227805666726",
+        "3764912080673684302013581157637719962419389932363407643392726637929900027361"
+    );
+    test_stable_digests!(
+        4,
+        3,
+        "987410845231211883628843697651240637503668173153010472627885317047972595007",
+        "10643252399206774558348214217109539965435270386044526686039580832685848229808"
+    );
+    test_stable_digests!(
+        5,
+        4,
+        "17080860765767836543827326441818364810648145719414397716988832461850029978837",
+        "1335336478355670444556306859968880474685088072799872920702097928794354525990"
+    );
+    test_stable_digests!(
+        6,
+        5,
+        "8056354258949383846649954223567997364643841153609478780225426716004712989487",
+        "10615738336792620440601334856066049416640839529425901803084456769328241115608"
+    );
+    test_stable_digests!(
+        7,
+        6,
+        "8444218162840939641860680895803344069527647508774615679624381626865560181350",
+        "15574260209226524962501348607128716660136926040859264993572927694270338608342"
+    );
+    test_stable_digests!(
+        8,
+        7,
+        "9767000612855292787951403162875538578402466732329315686781118572301768498406",
+        "12674902276383422103290389904780202470714741675711315422920670522033649976077"
+    );
+    test_stable_digests!(
+        9,
+        8,
+        "10294803483151206014655069209052805744356191104640568621051422769880511711851",
+        "5370003271073819481540939727743650023699314743879210477815274892948074272668"
+    );
+    test_stable_digests!(
+        10,
+        9,
+        "9824909699864080425712402230598313041221494157902037070335936468040639749351",
+        "1526340768237674656055494776569701753577515480673794282736834015246091091306"
+    );
 }
@@ -0,0 +1,109 @@
+use halo2curves_axiom::group::ff::PrimeField;
+use std::collections::HashMap;
+
+use crate::{Poseidon, Spec};
+
+impl<F: PrimeField, const T: usize, const RATE: usize> Spec<F, T, RATE> {
+    /// Hashes the parameters with a hasher built on this spec. Number of full
+    /// rounds, sbox exponent and number of partial rounds are absorbed first,
+    /// followed by optimized round constants, the MDS matrix, the pre sparse
+    /// matrix and sparse matrices, so specs that differ in any constant have
+    /// different fingerprints
+    pub fn fingerprint(&self) -> F {
+        let mut hasher = Poseidon::from_spec(self.clone());
+        hasher.update(&[
+            F::from(self.r_f as u64),
+            F::from(self.alpha),
+            F::from(self.constants.partial.len() as u64),
+        ]);
+        for constants in self.constants.start.iter() {
+            hasher.update(constants);
+        }
+        hasher.update(&self.constants.partial);
+        for constants in self.constants.end.iter() {
+            hasher.update(constants);
+        }
+        for matrix in [&self.mds_matrices.mds, &self.mds_matrices.pre_sparse_mds] {
+            for row in matrix.rows().iter() {
+                hasher.update(row);
+            }
+        }
+        for matrix in self.mds_matrices.sparse_matrices.iter() {
+            hasher.update(matrix.row());
+            hasher.update(matrix.col_hat());
+        }
+        hasher.squeeze()
+    }
+}
+
+/// Specs registered by their `fingerprint`, so that serialized data can refer
+/// to a spec with a single field element instead of embedding its constants
+#[derive(Debug, Clone, Default)]
+pub struct SpecRegistry<F: PrimeField, const T: usize, const RATE: usize> {
+    specs: HashMap<Vec<u8>, Spec<F, T, RATE>>,
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize> SpecRegistry<F, T, RATE> {
+    /// Constructs an empty registry
+    pub fn new() -> Self {
+        Self {
+            specs: HashMap::new(),
+        }
+    }
+
+    /// Registers the spec and returns its fingerprint. Registering the same
+    /// spec again has no effect
+    pub fn register(&mut self, spec: Spec<F, T, RATE>) -> F {
+        let fingerprint = spec.fingerprint();
+        self.specs
+            .insert(fingerprint.to_repr().as_ref().to_vec(), spec);
+        fingerprint
+    }
+
+    /// Returns the spec with given fingerprint if it is registered
+    pub fn get(&self, fingerprint: &F) -> Option<&Spec<F, T, RATE>> {
+        self.specs.get(fingerprint.to_repr().as_ref())
+    }
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize> Poseidon<F, T, RATE> {
+    /// Constructs a clear state hasher with the registered spec of given
+    /// fingerprint. Returns `None` if no such spec is registered
+    pub fn from_fingerprint(registry: &SpecRegistry<F, T, RATE>, fingerprint: &F) -> Option<Self> {
+        registry.get(fingerprint).cloned().map(Self::from_spec)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SpecRegistry;
+    use crate::{Poseidon, Spec};
+    use halo2curves_axiom::bn256::Fr;
+    use halo2curves_axiom::group::ff::PrimeField;
+
+    #[test]
+    fn test_spec_registry() {
+        let spec = Spec::<Fr, 3, 2>::new(8, 57);
+        let mut registry = SpecRegistry::new();
+        let fingerprint = registry.register(spec.clone());
+        assert_eq!(fingerprint, spec.fingerprint());
+        assert_eq!(registry.register(spec.clone()), fingerprint);
+
+        let other = Spec::<Fr, 3, 2>::new(8, 56);
+        let other_fingerprint = registry.register(other.clone());
+        assert_ne!(fingerprint, other_fingerprint);
+        assert_eq!(registry.get(&other_fingerprint), Some(&other));
+
+        // Reference to the spec is serialized as the fingerprint alone
+        let reference = fingerprint.to_repr();
+        let fingerprint = Fr::from_repr(reference).unwrap();
+        let mut hasher = Poseidon::from_fingerprint(&registry, &fingerprint).unwrap();
+        let mut expected = Poseidon::<Fr, 3, 2>::new(8, 57);
+        assert!(hasher.same_config(&expected));
+        hasher.update(&[Fr::from(1)]);
+        expected.update(&[Fr::from(1)]);
+        assert_eq!(hasher.squeeze(), expected.squeeze());
+
+        assert!(Poseidon::from_fingerprint(&registry, &Fr::from(0)).is_none());
+    }
+}
@@ -0,0 +1,432 @@
+//! Serde support enabled with the `serde` feature. Field elements are
+//! serialized as their canonical byte representation so the format doesn't
+//! depend on the internal representation of the field
+
+use crate::bytes::bytes_per_element;
+use crate::config::PoseidonConfig;
+use crate::matrix::Matrix;
+use crate::spec::{MDSMatrices, MDSMatrix, OptimizedConstants, SparseMDSMatrix, Spec, State};
+use crate::{Poseidon, ResultMode};
+use alloc::vec::Vec;
+use halo2curves_axiom::group::ff::PrimeField;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+fn to_bytes<F: PrimeField>(words: &[F]) -> Vec<Vec<u8>> {
+    words
+        .iter()
+        .map(|word| word.to_repr().as_ref().to_vec())
+        .collect()
+}
+
+fn from_bytes<F: PrimeField, E: Error>(words: Vec<Vec<u8>>) -> Result<Vec<F>, E> {
+    words
+        .into_iter()
+        .map(|bytes| {
+            let mut repr = F::Repr::default();
+            if repr.as_ref().len() != bytes.len() {
+                return Err(E::custom("invalid field element length"));
+            }
+            repr.as_mut().copy_from_slice(&bytes);
+            Option::from(F::from_repr(repr)).ok_or_else(|| E::custom("non canonical field element"))
+        })
+        .collect()
+}
+
+fn array_from_bytes<F: PrimeField, E: Error, const N: usize>(
+    words: Vec<Vec<u8>>,
+) -> Result<[F; N], E> {
+    from_bytes(words)?
+        .try_into()
+        .map_err(|_| E::custom("invalid number of field elements"))
+}
+
+impl<F: PrimeField, const T: usize> Serialize for State<F, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        to_bytes(&self.0).serialize(serializer)
+    }
+}
+
+impl<'de, F: PrimeField, const T: usize> Deserialize<'de> for State<F, T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        Ok(State(array_from_bytes(Vec::deserialize(deserializer)?)?))
+    }
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize> Serialize for MDSMatrix<F, T, RATE> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.rows()
+            .iter()
+            .map(|row| to_bytes(row))
+            .collect::<Vec<_>>()
+            .serialize(serializer)
+    }
+}
+
+impl<'de, F: PrimeField, const T: usize, const RATE: usize> Deserialize<'de>
+    for MDSMatrix<F, T, RATE>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let rows = Vec::<Vec<Vec<u8>>>::deserialize(deserializer)?
+            .into_iter()
+            .map(array_from_bytes)
+            .collect::<Result<Vec<[F; T]>, _>>()?;
+        let rows = rows
+            .try_into()
+            .map_err(|_| D::Error::custom("invalid number of rows"))?;
+        Ok(MDSMatrix(Matrix(rows)))
+    }
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize> Serialize for SparseMDSMatrix<F, T, RATE> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (to_bytes(&self.row), to_bytes(&self.col_hat)).serialize(serializer)
+    }
+}
+
+impl<'de, F: PrimeField, const T: usize, const RATE: usize> Deserialize<'de>
+    for SparseMDSMatrix<F, T, RATE>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (row, col_hat) = <(Vec<Vec<u8>>, Vec<Vec<u8>>)>::deserialize(deserializer)?;
+        Ok(SparseMDSMatrix {
+            row: array_from_bytes(row)?,
+            col_hat: array_from_bytes(col_hat)?,
+        })
+    }
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize> Serialize for MDSMatrices<F, T, RATE> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (&self.mds, &self.pre_sparse_mds, &self.sparse_matrices).serialize(serializer)
+    }
+}
+
+impl<'de, F: PrimeField, const T: usize, const RATE: usize> Deserialize<'de>
+    for MDSMatrices<F, T, RATE>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (mds, pre_sparse_mds, sparse_matrices) = Deserialize::deserialize(deserializer)?;
+        Ok(MDSMatrices {
+            mds,
+            pre_sparse_mds,
+            sparse_matrices,
+        })
+    }
+}
+
+impl<F: PrimeField, const T: usize> Serialize for OptimizedConstants<F, T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let full = |constants: &Vec<[F; T]>| {
+            constants
+                .iter()
+                .map(|constants| to_bytes(constants))
+                .collect::<Vec<_>>()
+        };
+        (full(&self.start), to_bytes(&self.partial), full(&self.end)).serialize(serializer)
+    }
+}
+
+impl<'de, F: PrimeField, const T: usize> Deserialize<'de> for OptimizedConstants<F, T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (start, partial, end) =
+            <(Vec<Vec<Vec<u8>>>, Vec<Vec<u8>>, Vec<Vec<Vec<u8>>>)>::deserialize(deserializer)?;
+        let full = |constants: Vec<Vec<Vec<u8>>>| {
+            constants
+                .into_iter()
+                .map(array_from_bytes)
+                .collect::<Result<Vec<[F; T]>, D::Error>>()
+        };
+        Ok(OptimizedConstants {
+            start: full(start)?,
+            partial: from_bytes(partial)?,
+            end: full(end)?,
+        })
+    }
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize> Serialize for Spec<F, T, RATE> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (self.r_f, self.alpha, &self.mds_matrices, &self.constants).serialize(serializer)
+    }
+}
+
+impl<'de, F: PrimeField, const T: usize, const RATE: usize> Deserialize<'de> for Spec<F, T, RATE> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (r_f, alpha, mds_matrices, constants): (
+            usize,
+            u64,
+            MDSMatrices<F, T, RATE>,
+            OptimizedConstants<F, T>,
+        ) = Deserialize::deserialize(deserializer)?;
+
+        Spec::from_constants(r_f, alpha, mds_matrices, constants).map_err(D::Error::custom)
+    }
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize> Serialize for Poseidon<F, T, RATE> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        (
+            &self.state,
+            &self.spec,
+            to_bytes(&self.absorbing),
+            self.absorbed,
+            (self.arity, self.domain.map(|domain| to_bytes(&[domain]))),
+            self.finalized,
+            match self.result_mode {
+                ResultMode::Single => 0u8,
+                ResultMode::Sum => 1,
+            },
+            (
+                &self.pending_bytes,
+                self.absorbed_bytes,
+                self.checksum,
+                self.message_length,
+                self.squeeze_rate,
+            ),
+        )
+            .serialize(serializer)
+    }
+}
+
+impl<'de, F: PrimeField, const T: usize, const RATE: usize> Deserialize<'de>
+    for Poseidon<F, T, RATE>
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[allow(clippy::type_complexity)]
+        let (state, spec, absorbing, absorbed, (arity, domain), finalized, result_mode, bytes): (
+            State<F, T>,
+            Spec<F, T, RATE>,
+            Vec<Vec<u8>>,
+            usize,
+            (Option<usize>, Option<Vec<Vec<u8>>>),
+            bool,
+            u8,
+            (Vec<u8>, Option<u64>, Option<u64>, Option<usize>, usize),
+        ) = Deserialize::deserialize(deserializer)?;
+
+        let result_mode = match result_mode {
+            0 => ResultMode::Single,
+            1 => ResultMode::Sum,
+            _ => return Err(D::Error::custom("invalid result mode")),
+        };
+        let absorbing: Vec<F> = from_bytes(absorbing)?;
+        if absorbing.len() >= RATE {
+            return Err(D::Error::custom("absorption line exceeds the rate"));
+        }
+        let (pending_bytes, absorbed_bytes, checksum, message_length, squeeze_rate) = bytes;
+        let domain = domain
+            .map(|domain| array_from_bytes::<F, D::Error, 1>(domain))
+            .transpose()?
+            .map(|[domain]| domain);
+        if pending_bytes.len() >= bytes_per_element::<F>() {
+            return Err(D::Error::custom("pending bytes exceed an element"));
+        }
+        if !(1..=RATE).contains(&squeeze_rate) {
+            return Err(D::Error::custom("invalid squeeze rate"));
+        }
+
+        Ok(Poseidon {
+            state,
+            spec,
+            absorbing,
+            absorbed,
+            arity,
+            finalized,
+            result_mode,
+            pending_bytes,
+            absorbed_bytes,
+            domain,
+            checksum,
+            message_length,
+            squeeze_rate,
+            #[cfg(feature = "debug-transcript")]
+            transcript: Vec::new(),
+        })
+    }
+}
+
+impl<F: PrimeField> Serialize for PoseidonConfig<F> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let rows = |rows: &Vec<Vec<F>>| rows.iter().map(|row| to_bytes(row)).collect::<Vec<_>>();
+        (
+            self.t,
+            self.rate,
+            self.alpha,
+            self.r_f,
+            self.r_p,
+            rows(&self.round_constants),
+            rows(&self.mds),
+        )
+            .serialize(serializer)
+    }
+}
+
+impl<'de, F: PrimeField> Deserialize<'de> for PoseidonConfig<F> {
+    /// Only field elements are validated here. Consistency of parameters is
+    /// checked when the hasher is built
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[allow(clippy::type_complexity)]
+        let (t, rate, alpha, r_f, r_p, round_constants, mds): (
+            usize,
+            usize,
+            u64,
+            usize,
+            usize,
+            Vec<Vec<Vec<u8>>>,
+            Vec<Vec<Vec<u8>>>,
+        ) = Deserialize::deserialize(deserializer)?;
+        let rows = |rows: Vec<Vec<Vec<u8>>>| {
+            rows.into_iter()
+                .map(from_bytes)
+                .collect::<Result<Vec<Vec<F>>, D::Error>>()
+        };
+
+        Ok(PoseidonConfig {
+            t,
+            rate,
+            alpha,
+            r_f,
+            r_p,
+            round_constants: rows(round_constants)?,
+            mds: rows(mds)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Poseidon, PoseidonConfig, Spec, State};
+    use digest::{FixedOutput, Update};
+    use halo2curves_axiom::bn256::Fr;
+    use halo2curves_axiom::group::ff::Field;
+    use rand_core::OsRng;
+
+    const R_F: usize = 8;
+    const R_P: usize = 57;
+    const T: usize = 5;
+    const RATE: usize = 4;
+
+    fn gen_random_vec(len: usize) -> Vec<Fr> {
+        (0..len).map(|_| Fr::random(OsRng)).collect::<Vec<Fr>>()
+    }
+
+    #[test]
+    fn test_serialize_absorb_then_squeeze() {
+        for number_of_inputs in 0..3 * RATE {
+            let inputs_0 = gen_random_vec(number_of_inputs);
+            let inputs_1 = gen_random_vec(RATE + 1);
+
+            let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+            poseidon.update(&inputs_0);
+            let bytes = bincode::serialize(&poseidon).unwrap();
+
+            let mut restored: Poseidon<Fr, T, RATE> = bincode::deserialize(&bytes).unwrap();
+            assert!(restored.same_config(&poseidon));
+            assert_eq!(restored.squeeze(), poseidon.clone().squeeze());
+
+            // Continue absorbing after restoring
+            let mut restored: Poseidon<Fr, T, RATE> = bincode::deserialize(&bytes).unwrap();
+            restored.update(&inputs_1);
+            poseidon.update(&inputs_1);
+            assert_eq!(restored.squeeze(), poseidon.squeeze());
+        }
+
+        // Fixed length hashers keep the arity and the number of absorbed elements
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new_fixed_length(RATE + 1, R_F, R_P);
+        poseidon.update(&gen_random_vec(RATE));
+        let bytes = bincode::serialize(&poseidon).unwrap();
+        let mut restored: Poseidon<Fr, T, RATE> = bincode::deserialize(&bytes).unwrap();
+        assert!(restored.try_squeeze().is_err());
+        let last = gen_random_vec(1);
+        restored.update(&last);
+        poseidon.update(&last);
+        assert_eq!(restored.squeeze(), poseidon.squeeze());
+
+        // Domain is kept across reset
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new_with_domain(R_F, R_P, Fr::from(3));
+        poseidon.update(&gen_random_vec(2));
+        let bytes = bincode::serialize(&poseidon).unwrap();
+        let mut restored: Poseidon<Fr, T, RATE> = bincode::deserialize(&bytes).unwrap();
+        assert!(restored.same_config(&poseidon));
+        restored.reset();
+        poseidon.reset();
+        assert_eq!(restored.squeeze(), poseidon.squeeze());
+
+        // Squeeze rate is kept
+        let mut poseidon = Poseidon::<Fr, T, RATE>::new(R_F, R_P).with_squeeze_rate(1);
+        poseidon.update(&gen_random_vec(2));
+        let bytes = bincode::serialize(&poseidon).unwrap();
+        let mut restored: Poseidon<Fr, T, RATE> = bincode::deserialize(&bytes).unwrap();
+        assert!(restored.same_config(&poseidon));
+        assert_eq!(restored.squeeze_n(3), poseidon.squeeze_n(3));
+    }
+
+    #[test]
+    fn test_serialize_transcript_checkpoints() {
+        let rounds = (0..4)
+            .map(|i| gen_random_vec(2 * i + 1))
+            .collect::<Vec<Vec<Fr>>>();
+        let bytes = (0..45).map(|i| i as u8).collect::<Vec<u8>>();
+
+        // Hasher is persisted and reloaded between every round
+        let mut expected = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        let mut checkpoint = bincode::serialize(&expected).unwrap();
+        for inputs in rounds.iter() {
+            let mut poseidon: Poseidon<Fr, T, RATE> = bincode::deserialize(&checkpoint).unwrap();
+            poseidon.update(inputs);
+            expected.update(inputs);
+            assert_eq!(
+                poseidon.challenge_and_absorb(),
+                expected.challenge_and_absorb()
+            );
+            poseidon.update(&inputs[..1]);
+            expected.update(&inputs[..1]);
+            checkpoint = bincode::serialize(&poseidon).unwrap();
+        }
+
+        // Including bytes waiting to fill an element
+        let mut poseidon: Poseidon<Fr, T, RATE> = bincode::deserialize(&checkpoint).unwrap();
+        Update::update(&mut poseidon, &bytes[..40]);
+        Update::update(&mut expected, &bytes[..40]);
+        let mut poseidon: Poseidon<Fr, T, RATE> =
+            bincode::deserialize(&bincode::serialize(&poseidon).unwrap()).unwrap();
+        Update::update(&mut poseidon, &bytes[40..]);
+        Update::update(&mut expected, &bytes[40..]);
+        assert_eq!(poseidon.finalize_fixed(), expected.finalize_fixed());
+    }
+
+    #[test]
+    fn test_serialize_spec_and_state() {
+        let spec = Spec::<Fr, T, RATE>::new(R_F, R_P);
+        let bytes = bincode::serialize(&spec).unwrap();
+        let restored: Spec<Fr, T, RATE> = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(restored, spec);
+
+        let state = State::<Fr, T>(gen_random_vec(T).try_into().unwrap());
+        let bytes = bincode::serialize(&state).unwrap();
+        let restored: State<Fr, T> = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(restored, state);
+
+        // Width must match
+        assert!(bincode::deserialize::<State<Fr, 3>>(&bytes).is_err());
+        // Elements must be canonical
+        let bytes = bincode::serialize(&vec![vec![0xffu8; 32]; T]).unwrap();
+        assert!(bincode::deserialize::<State<Fr, T>>(&bytes).is_err());
+    }
+
+    #[test]
+    fn test_serialize_config() {
+        let config = PoseidonConfig::<Fr>::generate::<T, RATE>(R_F, R_P, 5).unwrap();
+        let bytes = bincode::serialize(&config).unwrap();
+        let restored: PoseidonConfig<Fr> = bincode::deserialize(&bytes).unwrap();
+        assert_eq!(restored, config);
+
+        let inputs = gen_random_vec(RATE);
+        let mut poseidon = restored.build::<T, RATE>().unwrap();
+        poseidon.update(&inputs);
+        let mut expected = Poseidon::<Fr, T, RATE>::new(R_F, R_P);
+        expected.update(&inputs);
+        assert_eq!(poseidon.squeeze(), expected.squeeze());
+    }
+}
@@ -1,6 +1,19 @@
-use crate::{grain::Grain, matrix::Matrix};
+use crate::bytes::{cmp_canonical, from_hex, write_be};
+use crate::{grain::Grain, matrix::Matrix, Error};
+use alloc::string::String;
+use alloc::vec;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::ops::Index;
 use halo2curves_axiom::group::ff::{FromUniformBytes, PrimeField};
-use std::ops::Index;
+use subtle::Choice;
+
+/// Domain tags added to the capacity of the two to one compression for
+/// nonces and set digests. They are the ASCII strings `nonce` and `set` read
+/// as big endian integers, and since they are below `2**63` the tagged
+/// capacity never collides with the capacity of a fixed length hash
+const NONCE_TAG: u64 = u64::from_be_bytes(*b"\0\0\0nonce");
+const SET_TAG: u64 = u64::from_be_bytes(*b"\0\0\0\0\0set");
 
 /// `State` is structure `T` sized field elements that are subjected to
 /// permutation
@@ -17,22 +30,41 @@ impl<F: PrimeField, const T: usize> Default for State<F, T> {
 }
 
 impl<F: PrimeField, const T: usize> State<F, T> {
-    /// Applies sbox for all elements of the state.
-    /// Only supports `alpha = 5` sbox case.
-    pub(crate) fn sbox_full(&mut self) {
+    /// Initial state for fixed length hashing. The capacity value is
+    /// `2**64 * l + 2**63` where `l` is the input length. The offset keeps
+    /// length one apart from the variable length capacity `2**64`
+    pub(crate) fn fixed_length(length: usize) -> Self {
+        let mut state = Self::constant_length(length);
+        state.0[0] += F::from(1 << 63);
+        state
+    }
+
+    /// Initial state of the constant length hashing of circuit gadgets. The
+    /// capacity value is `2**64 * l` where `l` is the input length, which is
+    /// equal to the variable length capacity for length one
+    pub(crate) fn constant_length(length: usize) -> Self {
+        let mut state = [F::ZERO; T];
+        state[0] = F::from(length as u64) * F::from_u128(1 << 64);
+        State(state)
+    }
+
+    /// Initial state with given capacity element and zero rate
+    pub(crate) fn with_capacity(capacity: F) -> Self {
+        let mut state = [F::ZERO; T];
+        state[0] = capacity;
+        State(state)
+    }
+
+    /// Applies sbox for all elements of the state
+    pub(crate) fn sbox_full(&mut self, alpha: u64) {
         for e in self.0.iter_mut() {
-            let tmp = e.mul(*e);
-            e.mul_assign(tmp);
-            e.mul_assign(tmp);
+            sbox(e, alpha);
         }
     }
 
-    /// Partial round sbox applies sbox to the first element of the state.
-    /// Only supports `alpha = 5` sbox case
-    pub(crate) fn sbox_part(&mut self) {
-        let tmp = self.0[0].mul(self.0[0]);
-        self.0[0].mul_assign(tmp);
-        self.0[0].mul_assign(tmp);
+    /// Partial round sbox applies sbox to the first element of the state
+    pub(crate) fn sbox_part(&mut self, alpha: u64) {
+        sbox(&mut self.0[0], alpha);
     }
 
     /// Adds constants to all elements of the state
@@ -58,8 +90,33 @@ impl<F: PrimeField, const T: usize> State<F, T> {
     pub(crate) fn result(&self) -> F {
         self.0[1]
     }
+
+    /// Cheap non cryptographic summary of the state to be used in trace events
+    #[cfg(feature = "tracing")]
+    pub(crate) fn trace_fingerprint(&self) -> u64 {
+        self.0.iter().fold(0, |acc, word| {
+            let mut bytes = [0u8; 8];
+            bytes.copy_from_slice(&word.to_repr().as_ref()[..8]);
+            acc.rotate_left(8) ^ u64::from_le_bytes(bytes)
+        })
+    }
+}
+
+/// Raises the element to the power of `alpha` with a shortcut for the default
+/// `alpha = 5` case
+fn sbox<F: PrimeField>(e: &mut F, alpha: u64) {
+    if alpha == DEFAULT_ALPHA {
+        let tmp = e.mul(*e);
+        e.mul_assign(tmp);
+        e.mul_assign(tmp);
+    } else {
+        *e = e.pow_vartime([alpha]);
+    }
 }
 
+/// Default sbox exponent which is valid for BN254 scalar field
+pub(crate) const DEFAULT_ALPHA: u64 = 5;
+
 /// `Spec` holds construction parameters as well as constants that are used in
 /// permutation step. Constants are planned to be hardcoded once transcript
 /// design matures. Number of partial rounds can be deriven from number of
@@ -67,8 +124,24 @@ impl<F: PrimeField, const T: usize> State<F, T> {
 #[derive(Debug, Clone)]
 pub struct Spec<F: PrimeField, const T: usize, const RATE: usize> {
     pub(crate) r_f: usize,
+    pub(crate) alpha: u64,
     pub(crate) mds_matrices: MDSMatrices<F, T, RATE>,
     pub(crate) constants: OptimizedConstants<F, T>,
+    /// Unoptimized round constants, one row per round. Empty if the spec is
+    /// constructed from optimized constants
+    pub(crate) round_constants: Vec<[F; T]>,
+}
+
+/// Specs are equal if they define the same permutation. Unoptimized round
+/// constants are not compared, since they are not known for specs
+/// constructed from optimized constants
+impl<F: PrimeField, const T: usize, const RATE: usize> PartialEq for Spec<F, T, RATE> {
+    fn eq(&self, other: &Self) -> bool {
+        self.r_f == other.r_f
+            && self.alpha == other.alpha
+            && self.mds_matrices == other.mds_matrices
+            && self.constants == other.constants
+    }
 }
 
 impl<F: PrimeField, const T: usize, const RATE: usize> Spec<F, T, RATE> {
@@ -76,20 +149,190 @@ impl<F: PrimeField, const T: usize, const RATE: usize> Spec<F, T, RATE> {
     pub fn r_f(&self) -> usize {
         self.r_f
     }
+    /// Exponent of the sbox
+    pub fn alpha(&self) -> u64 {
+        self.alpha
+    }
     /// Set of MDS Matrices used in permutation line
     pub fn mds_matrices(&self) -> &MDSMatrices<F, T, RATE> {
         &self.mds_matrices
     }
+
     /// Optimised round constants
     pub fn constants(&self) -> &OptimizedConstants<F, T> {
         &self.constants
     }
+
+    /// Constructs a spec from constants of another spec, for example ones
+    /// returned by `constants` and `mds_matrices`, without generating them
+    /// again. Fails if the number of full rounds is not positive and even, if
+    /// the shape of the constants doesn't match it or if the sbox exponent is
+    /// invalid
+    pub fn from_constants(
+        r_f: usize,
+        alpha: u64,
+        mds_matrices: MDSMatrices<F, T, RATE>,
+        constants: OptimizedConstants<F, T>,
+    ) -> Result<Self, Error> {
+        let r_f_half = r_f / 2;
+        if r_f_half == 0 || r_f != 2 * r_f_half {
+            return Err(Error::InvalidRounds { r_f });
+        }
+        for (expected, actual) in [
+            (r_f_half + 1, constants.start.len()),
+            (r_f_half - 1, constants.end.len()),
+            (mds_matrices.sparse_matrices.len(), constants.partial.len()),
+        ] {
+            if expected != actual {
+                return Err(Error::InvalidConstantsShape { expected, actual });
+            }
+        }
+        if !is_valid_alpha::<F>(alpha) {
+            return Err(Error::InvalidAlpha { alpha });
+        }
+
+        Ok(Self {
+            r_f,
+            alpha,
+            mds_matrices,
+            constants,
+            round_constants: Vec::new(),
+        })
+    }
+
+    /// Compresses two elements into one with a single permutation. It is the
+    /// fixed length hashing of two elements as in
+    /// `Poseidon::new_fixed_length(2, ..)`
+    pub fn hash_two(&self, a: F, b: F) -> F {
+        self.hash_two_tagged(0, a, b)
+    }
+
+    /// `hash_two` with `tag` added to the capacity, which separates the
+    /// domain of the compression without an extra permutation
+    fn hash_two_tagged(&self, tag: u64, a: F, b: F) -> F {
+        assert!(RATE >= 2);
+        let mut state = State::fixed_length(2);
+        state.0[0] += F::from(tag);
+        state.0[1] = a;
+        state.0[2] = b;
+        self.permute(&mut state);
+        state.result()
+    }
+
+    /// Returns the `bytes` least significant bytes of the big endian
+    /// representation of `hash_two(a, b)`, that is a suffix of the full
+    /// digest. Truncation to `n` bytes leaves about `4 * n` bits of collision
+    /// resistance and `8 * n` bits of preimage resistance, so short outputs
+    /// should only be used where that is acceptable. Panics if `bytes` is
+    /// larger than the field representation
+    pub fn hash_two_truncated(&self, a: F, b: F, bytes: usize) -> Vec<u8> {
+        let digest = self.hash_two(a, b);
+        let len = digest.to_repr().as_ref().len();
+        assert!(bytes <= len);
+        let mut out = vec![0; len];
+        write_be(&digest, &mut out);
+        out.split_off(len - bytes)
+    }
+
+    /// Compresses two digests with `hash_two`. If `order_matters` is false
+    /// digests are sorted by their integer values first, so the result is
+    /// commutative. Note that in the commutative mode `(a, b)` and `(b, a)`
+    /// collide by design, and the output for a pair is equal to the ordered
+    /// output for the same pair in sorted order, so modes shouldn't be mixed
+    /// in a single context. Expects little endian field representation
+    pub fn concat_hash(&self, a: F, b: F, order_matters: bool) -> F {
+        if !order_matters && cmp_canonical(&a, &b) == Ordering::Greater {
+            self.hash_two(b, a)
+        } else {
+            self.hash_two(a, b)
+        }
+    }
+
+    /// Commits to `value` with a random `nonce` as `hash_two(value, nonce)`.
+    /// Nonce must be secret and uniformly random for the commitment to hide
+    /// the value and must not be reused
+    pub fn commit_value(&self, value: F, nonce: F) -> F {
+        self.hash_two(value, nonce)
+    }
+
+    /// Checks in constant time that revealed `value` and `nonce` open the
+    /// `commitment`
+    pub fn reveal_check(&self, commitment: F, value: F, nonce: F) -> bool {
+        self.commit_value(value, nonce).ct_eq(&commitment).into()
+    }
+
+    /// Checks a chain of nested commitments where each level commits to the
+    /// previous commitment. Openings are `(value, nonce)` pairs from the
+    /// innermost level out, each value after the first must be the
+    /// `commit_value` of the previous opening and the last opening must open
+    /// `final_commitment`. Comparisons are constant time and an empty chain
+    /// is rejected
+    pub fn verify_chain(&self, final_commitment: F, openings: &[(F, F)]) -> bool {
+        let mut valid = Choice::from(!openings.is_empty() as u8);
+        let mut commitment = None;
+        for (value, nonce) in openings.iter() {
+            if let Some(previous) = commitment {
+                valid &= value.ct_eq(&previous);
+            }
+            commitment = Some(self.commit_value(*value, *nonce));
+        }
+        let commitment = commitment.unwrap_or(final_commitment);
+        (valid & commitment.ct_eq(&final_commitment)).into()
+    }
+
+    /// Derives a signing nonce from `private_key` and `message_hash` in that
+    /// order, so the same key and message always give the same nonce without
+    /// an RNG. The compression is tagged with a nonce domain, so a nonce is
+    /// never equal to `hash_two` or a commitment over the same pair. Private
+    /// key must stay secret since anyone who knows it can recompute the nonce
+    pub fn deterministic_nonce(&self, private_key: F, message_hash: F) -> F {
+        self.hash_two_tagged(NONCE_TAG, private_key, message_hash)
+    }
+
+    /// Folds `element` into the running set digest `current`. The compression
+    /// is tagged with a set domain, so set digests don't collide with
+    /// `hash_two` over the same pair
+    pub fn accumulate_set(&self, current: F, element: F) -> F {
+        self.hash_two_tagged(SET_TAG, current, element)
+    }
+
+    /// Returns an order independent digest of a set. Elements are sorted by
+    /// their integer values, duplicates are removed and the rest is folded
+    /// with `accumulate_set` starting from zero. This is a hash commitment to
+    /// the whole set rather than an RSA style accumulator, so membership of
+    /// a single element can't be proven without revealing the set. Expects
+    /// little endian field representation
+    pub fn set_digest(&self, elements: &[F]) -> F {
+        let mut elements = elements.to_vec();
+        elements.sort_by(cmp_canonical);
+        elements.dedup();
+        elements.into_iter().fold(F::ZERO, |current, element| {
+            self.accumulate_set(current, element)
+        })
+    }
+
+    /// Returns `hash_two(base, F::from(i))` for each `i` in `0..n`. The state
+    /// with the capacity and `base` is prepared once and each tag only sets
+    /// the index and applies a single permutation
+    pub fn tag_range(&self, base: F, n: usize) -> Vec<F> {
+        assert!(RATE >= 2);
+        let mut prefix = State::fixed_length(2);
+        prefix.0[1] = base;
+        (0..n)
+            .map(|i| {
+                let mut state = prefix.clone();
+                state.0[2] = F::from(i as u64);
+                self.permute(&mut state);
+                state.result()
+            })
+            .collect()
+    }
 }
 
 /// `OptimizedConstants` has round constants that are added each round. While
 /// full rounds has T sized constants there is a single constant for each
 /// partial round
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct OptimizedConstants<F: PrimeField, const T: usize> {
     pub(crate) start: Vec<[F; T]>,
     pub(crate) partial: Vec<F>,
@@ -116,7 +359,7 @@ impl<F: PrimeField, const T: usize> OptimizedConstants<F, T> {
 /// `MDSMatrices` holds the MDS matrix as well as transition matrix which is
 /// also called `pre_sparse_mds` and sparse matrices that enables us to reduce
 /// number of multiplications in apply MDS step
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct MDSMatrices<F: PrimeField, const T: usize, const RATE: usize> {
     pub(crate) mds: MDSMatrix<F, T, RATE>,
     pub(crate) pre_sparse_mds: MDSMatrix<F, T, RATE>,
@@ -141,7 +384,7 @@ impl<F: PrimeField, const T: usize, const RATE: usize> MDSMatrices<F, T, RATE> {
 }
 
 /// `MDSMatrix` is applied to `State` to achive linear layer of Poseidon
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, PartialEq)]
 pub struct MDSMatrix<F: PrimeField, const T: usize, const RATE: usize>(pub(crate) Matrix<F, T>);
 
 impl<F: PrimeField, const T: usize, const RATE: usize> Index<usize> for MDSMatrix<F, T, RATE> {
@@ -172,7 +415,7 @@ impl<F: PrimeField, const T: usize, const RATE: usize> MDSMatrix<F, T, RATE> {
     }
 
     /// Inverts the MDS matrix
-    fn invert(&self) -> Self {
+    pub(crate) fn invert(&self) -> Self {
         Self(self.0.invert())
     }
 
@@ -234,7 +477,7 @@ impl<F: PrimeField, const T: usize, const RATE: usize> MDSMatrix<F, T, RATE> {
 
 /// `SparseMDSMatrix` are in `[row], [hat | identity]` form and used in linear
 /// layer of partial rounds instead of the original MDS
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SparseMDSMatrix<F: PrimeField, const T: usize, const RATE: usize> {
     pub(crate) row: [F; T],
     pub(crate) col_hat: [F; RATE],
@@ -269,6 +512,30 @@ impl<F: PrimeField, const T: usize, const RATE: usize> SparseMDSMatrix<F, T, RAT
             *new_word = *col_el * words[0] + word;
         }
     }
+
+    /// Applies the inverse of the sparse MDS matrix to the state. Uses a
+    /// single field inversion
+    pub(crate) fn apply_inverse(&self, state: &mut State<F, T>) {
+        // Output words other than the first are `x_i + col_hat_i * x_0`, so
+        // the first row gives `x_0` after substituting the other inputs
+        let words = state.words();
+        let (num, den) = self
+            .row
+            .iter()
+            .skip(1)
+            .zip(self.col_hat.iter())
+            .zip(words.iter().skip(1))
+            .fold(
+                (words[0], self.row[0]),
+                |(num, den), ((e, col_el), word)| (num - *e * word, den - *e * col_el),
+            );
+        let first = num * den.invert().unwrap();
+
+        state.0[0] = first;
+        for (word, col_el) in (state.0).iter_mut().skip(1).zip(self.col_hat.iter()) {
+            *word -= *col_el * first;
+        }
+    }
 }
 
 impl<F: PrimeField, const T: usize, const RATE: usize> From<MDSMatrix<F, T, RATE>>
@@ -295,30 +562,167 @@ impl<F: PrimeField, const T: usize, const RATE: usize> From<MDSMatrix<F, T, RATE
     }
 }
 
-impl<F: FromUniformBytes<64>, const T: usize, const RATE: usize> Spec<F, T, RATE> {
+impl<F: PrimeField, const T: usize, const RATE: usize> Spec<F, T, RATE> {
     /// Given number of round parameters constructs new Posedion instance
     /// calculating unoptimized round constants with reference `Grain` then
     /// calculates optimized constants and sparse matrices
-    pub fn new(r_f: usize, r_p: usize) -> Self {
-        let (unoptimized_constants, mds) = Grain::generate(r_f, r_p);
-        let constants = Self::calculate_optimized_constants(r_f, r_p, unoptimized_constants, &mds);
+    pub fn new(r_f: usize, r_p: usize) -> Self
+    where
+        F: FromUniformBytes<64>,
+    {
+        Self::new_with_alpha(r_f, r_p, DEFAULT_ALPHA).unwrap()
+    }
+
+    /// Same as `new` but with sbox exponent `alpha`. Fails if `alpha` is not
+    /// greater than one or is not coprime to `p - 1`, since then the sbox and
+    /// so the permutation is not a bijection. Expects little endian field
+    /// representation
+    pub fn new_with_alpha(r_f: usize, r_p: usize, alpha: u64) -> Result<Self, Error>
+    where
+        F: FromUniformBytes<64>,
+    {
+        Self::new_with_uniform_bytes::<64>(r_f, r_p, alpha)
+    }
+
+    /// Same as `new_with_alpha` for fields that sample round constants from
+    /// `N` uniform bytes, for example the ones implementing
+    /// `FromUniformBytes<32>`. `Grain` output fits into `N` bytes for any
+    /// field of at most `8 * N` bits, so constants are the same for any such
+    /// `N`
+    pub fn new_with_uniform_bytes<const N: usize>(
+        r_f: usize,
+        r_p: usize,
+        alpha: u64,
+    ) -> Result<Self, Error>
+    where
+        F: FromUniformBytes<N>,
+    {
+        if !is_valid_alpha::<F>(alpha) {
+            return Err(Error::InvalidAlpha { alpha });
+        }
+
+        let (unoptimized_constants, mds) = Grain::generate::<N>(r_f, r_p);
+        Ok(Self::from_unoptimized(
+            r_f,
+            r_p,
+            alpha,
+            unoptimized_constants,
+            mds,
+        ))
+    }
+
+    /// Constructs a spec from round constants and the MDS matrix given as big
+    /// endian hex strings with optional `0x` prefix, for example as fetched
+    /// from a configuration service. There must be `r_f + r_p` rows of `T`
+    /// round constants and a `T x T` MDS matrix. Each string must encode a
+    /// canonical field element and the matrix must be MDS, that is all of its
+    /// square submatrices are non singular. Constants are then optimized as
+    /// in `new`. Sbox exponent is `alpha = 5`
+    pub fn from_hex_constants(
+        round_constants_hex: &[Vec<String>],
+        mds_hex: &[Vec<String>],
+        r_f: usize,
+        r_p: usize,
+    ) -> Result<Self, Error> {
+        Self::from_unoptimized_rows(
+            r_f,
+            r_p,
+            DEFAULT_ALPHA,
+            round_constants_hex,
+            mds_hex,
+            |hex: &String| from_hex(hex).ok_or_else(|| Error::InvalidHex { value: hex.clone() }),
+        )
+    }
+
+    /// Validates unoptimized parameters converting each element with
+    /// `convert` and constructs the spec
+    pub(crate) fn from_unoptimized_rows<A>(
+        r_f: usize,
+        r_p: usize,
+        alpha: u64,
+        round_constants: &[Vec<A>],
+        mds: &[Vec<A>],
+        convert: impl Fn(&A) -> Result<F, Error>,
+    ) -> Result<Self, Error> {
+        if T != RATE + 1 {
+            return Err(Error::InvalidWidth {
+                width: T,
+                rate: RATE,
+            });
+        }
+        if r_f == 0 || r_f % 2 == 1 {
+            return Err(Error::InvalidRounds { r_f });
+        }
+        if !is_valid_alpha::<F>(alpha) {
+            return Err(Error::InvalidAlpha { alpha });
+        }
+
+        let parse = |rows: &[Vec<A>], number_of_rows: usize| {
+            if rows.len() != number_of_rows {
+                return Err(Error::InvalidConstantsShape {
+                    expected: number_of_rows,
+                    actual: rows.len(),
+                });
+            }
+            rows.iter()
+                .map(|row| {
+                    if row.len() != T {
+                        return Err(Error::InvalidConstantsShape {
+                            expected: T,
+                            actual: row.len(),
+                        });
+                    }
+                    let mut words = [F::ZERO; T];
+                    for (word, e) in words.iter_mut().zip(row.iter()) {
+                        *word = convert(e)?;
+                    }
+                    Ok(words)
+                })
+                .collect::<Result<Vec<[F; T]>, Error>>()
+        };
+
+        let constants = parse(round_constants, r_f + r_p)?;
+        let mds = Matrix(parse(mds, T)?.try_into().unwrap());
+        if !mds.is_mds() {
+            return Err(Error::InvalidMds);
+        }
+
+        Ok(Self::from_unoptimized(
+            r_f,
+            r_p,
+            alpha,
+            constants,
+            MDSMatrix(mds),
+        ))
+    }
+
+    fn from_unoptimized(
+        r_f: usize,
+        r_p: usize,
+        alpha: u64,
+        unoptimized_constants: Vec<[F; T]>,
+        mds: MDSMatrix<F, T, RATE>,
+    ) -> Self {
+        let constants = Self::calculate_optimized_constants(r_f, r_p, &unoptimized_constants, &mds);
         let (sparse_matrices, pre_sparse_mds) = Self::calculate_sparse_matrices(r_p, &mds);
 
         Self {
             r_f,
+            alpha,
             constants,
             mds_matrices: MDSMatrices {
                 mds,
                 sparse_matrices,
                 pre_sparse_mds,
             },
+            round_constants: unoptimized_constants,
         }
     }
 
     fn calculate_optimized_constants(
         r_f: usize,
         r_p: usize,
-        constants: Vec<[F; T]>,
+        constants: &[[F; T]],
         mds: &MDSMatrix<F, T, RATE>,
     ) -> OptimizedConstants<F, T> {
         let inverse_mds = mds.invert();
@@ -390,13 +794,82 @@ impl<F: FromUniformBytes<64>, const T: usize, const RATE: usize> Spec<F, T, RATE
     }
 }
 
+/// Checks that `x -> x^alpha` is a non trivial permutation of the field.
+/// Expects little endian field representation
+pub(crate) fn is_valid_alpha<F: PrimeField>(alpha: u64) -> bool {
+    alpha > 1 && gcd(alpha, modulus_minus_one_rem::<F>(alpha)) == 1
+}
+
+/// Exponent `d` with `alpha * d = 1 mod p - 1` as little endian limbs, so
+/// that `x -> x^d` inverts the sbox. Expects a valid `alpha` and little
+/// endian field representation
+pub(crate) fn inverse_alpha<F: PrimeField>(alpha: u64) -> Vec<u64> {
+    debug_assert!(is_valid_alpha::<F>(alpha));
+    // Find `k` such that `k * (p - 1) + 1` is divisible by `alpha`
+    let rem = modulus_minus_one_rem::<F>(alpha) as u128;
+    let k = (1..alpha as u128)
+        .find(|k| (k * rem + 1).is_multiple_of(alpha as u128))
+        .unwrap();
+
+    let repr = (-F::ONE).to_repr();
+    let mut limbs = repr
+        .as_ref()
+        .chunks(8)
+        .map(|chunk| {
+            let mut bytes = [0u8; 8];
+            bytes[..chunk.len()].copy_from_slice(chunk);
+            u64::from_le_bytes(bytes)
+        })
+        .collect::<Vec<u64>>();
+    limbs.push(0);
+
+    let mut carry = 1u128;
+    for limb in limbs.iter_mut() {
+        let value = *limb as u128 * k + carry;
+        *limb = value as u64;
+        carry = value >> 64;
+    }
+    let mut rem = 0u128;
+    for limb in limbs.iter_mut().rev() {
+        let value = (rem << 64) | *limb as u128;
+        *limb = (value / alpha as u128) as u64;
+        rem = value % alpha as u128;
+    }
+    debug_assert_eq!(rem, 0);
+    limbs
+}
+
+/// Remainder of `p - 1` divided by `m`. Expects little endian field
+/// representation
+fn modulus_minus_one_rem<F: PrimeField>(m: u64) -> u64 {
+    (-F::ONE)
+        .to_repr()
+        .as_ref()
+        .iter()
+        .rev()
+        .fold(0u128, |rem, byte| ((rem << 8) + *byte as u128) % m as u128) as u64
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
 #[cfg(test)]
 pub(super) mod tests {
     use halo2curves_axiom::group::ff::{FromUniformBytes, PrimeField};
     use halo2curves_axiom::serde::SerdeObject;
 
-    use super::MDSMatrix;
+    use super::{is_valid_alpha, sbox, MDSMatrix, Spec, DEFAULT_ALPHA};
     use crate::grain::Grain;
+    use crate::Error;
+    use halo2curves_axiom::bn256::Fr;
+    use halo2curves_axiom::group::ff::Field;
+    use rand_core::OsRng;
+    use std::collections::HashSet;
 
     /// We want to keep non-optimized parameters to cross test with optimized
     /// one
@@ -409,7 +882,7 @@ pub(super) mod tests {
 
     impl<F: SerdeObject + FromUniformBytes<64>, const T: usize, const RATE: usize> SpecRef<F, T, RATE> {
         pub(crate) fn new(r_f: usize, r_p: usize) -> Self {
-            let (constants, mds) = Grain::generate(r_f, r_p);
+            let (constants, mds) = Grain::generate::<64>(r_f, r_p);
 
             SpecRef {
                 r_f,
@@ -419,4 +892,266 @@ pub(super) mod tests {
             }
         }
     }
+
+    #[test]
+    fn test_sbox_is_permutation_on_sample() {
+        // Sample includes negations so that even exponents must collide
+        let sample = (0..50000)
+            .map(|_| Fr::random(OsRng))
+            .flat_map(|e| [e, -e])
+            .collect::<Vec<Fr>>();
+        let count_distinct = |alpha: u64| {
+            sample
+                .iter()
+                .map(|e| {
+                    let mut e = *e;
+                    sbox(&mut e, alpha);
+                    e.to_repr()
+                })
+                .collect::<HashSet<_>>()
+                .len()
+        };
+
+        assert!(is_valid_alpha::<Fr>(DEFAULT_ALPHA));
+        assert_eq!(count_distinct(DEFAULT_ALPHA), sample.len());
+
+        assert!(!is_valid_alpha::<Fr>(2));
+        assert_eq!(count_distinct(2), sample.len() / 2);
+    }
+
+    #[test]
+    fn test_from_hex_constants() {
+        const R_F: usize = 8;
+        const R_P: usize = 57;
+        const T: usize = 3;
+        const RATE: usize = 2;
+
+        let to_hex = |e: &Fr| {
+            let repr = e.to_repr();
+            let digits = repr.iter().rev().map(|byte| format!("{:02x}", byte));
+            format!("0x{}", digits.collect::<String>())
+        };
+        let to_hex_rows = |rows: &[[Fr; T]]| {
+            rows.iter()
+                .map(|row| row.iter().map(to_hex).collect())
+                .collect::<Vec<Vec<String>>>()
+        };
+
+        let (constants, mds) = Grain::<Fr, T, RATE>::generate::<64>(R_F, R_P);
+        let (constants, mds) = (to_hex_rows(&constants), to_hex_rows(&mds.rows()));
+        let spec = Spec::<Fr, T, RATE>::from_hex_constants(&constants, &mds, R_F, R_P).unwrap();
+        assert_eq!(spec, Spec::new(R_F, R_P));
+
+        // Prefix and leading zeros are optional
+        let mut short = constants.clone();
+        short[0][0] = "1".to_string();
+        let mut expected = constants.clone();
+        expected[0][0] = format!("0x{}1", "0".repeat(63));
+        assert_eq!(
+            Spec::<Fr, T, RATE>::from_hex_constants(&short, &mds, R_F, R_P),
+            Spec::from_hex_constants(&expected, &mds, R_F, R_P)
+        );
+
+        let from_hex = |constants: &[Vec<String>], mds: &[Vec<String>], r_f: usize| {
+            Spec::<Fr, T, RATE>::from_hex_constants(constants, mds, r_f, R_P)
+        };
+        assert_eq!(
+            from_hex(&constants, &mds, R_F - 1),
+            Err(Error::InvalidRounds { r_f: R_F - 1 })
+        );
+        assert_eq!(
+            from_hex(&constants[1..], &mds, R_F),
+            Err(Error::InvalidConstantsShape {
+                expected: R_F + R_P,
+                actual: R_F + R_P - 1
+            })
+        );
+        let mut wide = mds.clone();
+        wide[1].push("0x1".to_string());
+        assert_eq!(
+            from_hex(&constants, &wide, R_F),
+            Err(Error::InvalidConstantsShape {
+                expected: T,
+                actual: T + 1
+            })
+        );
+
+        let modulus = "0x30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+        for invalid in ["", "0x", "0xg1", modulus] {
+            let mut constants = constants.clone();
+            constants[R_F][1] = invalid.to_string();
+            assert_eq!(
+                from_hex(&constants, &mds, R_F),
+                Err(Error::InvalidHex {
+                    value: invalid.to_string()
+                })
+            );
+        }
+
+        // Invertible but has a zero entry
+        let mut identity = vec![vec!["0x0".to_string(); T]; T];
+        for (i, row) in identity.iter_mut().enumerate() {
+            row[i] = "0x1".to_string();
+        }
+        assert_eq!(from_hex(&constants, &identity, R_F), Err(Error::InvalidMds));
+
+        assert_eq!(
+            Spec::<Fr, T, T>::from_hex_constants(&constants, &mds, R_F, R_P),
+            Err(Error::InvalidWidth { width: T, rate: T })
+        );
+    }
+
+    #[test]
+    fn test_tag_range() {
+        let spec = Spec::<Fr, 3, 2>::new(8, 57);
+        let base = Fr::random(OsRng);
+
+        assert!(spec.tag_range(base, 0).is_empty());
+        let tags = spec.tag_range(base, 100);
+        assert_eq!(tags.len(), 100);
+        for (i, tag) in tags.iter().enumerate() {
+            assert_eq!(*tag, spec.hash_two(base, Fr::from(i as u64)));
+        }
+    }
+
+    #[test]
+    fn test_concat_hash() {
+        let spec = Spec::<Fr, 3, 2>::new(8, 57);
+        let (small, large) = (Fr::from(1), -Fr::one());
+
+        assert_eq!(
+            spec.concat_hash(small, large, true),
+            spec.hash_two(small, large)
+        );
+        assert_eq!(
+            spec.concat_hash(large, small, true),
+            spec.hash_two(large, small)
+        );
+        assert_ne!(
+            spec.concat_hash(small, large, true),
+            spec.concat_hash(large, small, true)
+        );
+
+        assert_eq!(
+            spec.concat_hash(small, large, false),
+            spec.hash_two(small, large)
+        );
+        assert_eq!(
+            spec.concat_hash(large, small, false),
+            spec.hash_two(small, large)
+        );
+        for _ in 0..10 {
+            let (a, b) = (Fr::random(OsRng), Fr::random(OsRng));
+            assert_eq!(spec.concat_hash(a, b, false), spec.concat_hash(b, a, false));
+            assert_eq!(spec.concat_hash(a, a, false), spec.hash_two(a, a));
+        }
+    }
+
+    #[test]
+    fn test_hash_two_truncated() {
+        let spec = Spec::<Fr, 3, 2>::new(8, 57);
+        let (a, b) = (Fr::random(OsRng), Fr::random(OsRng));
+        let full = spec
+            .hash_two(a, b)
+            .to_repr()
+            .iter()
+            .rev()
+            .copied()
+            .collect::<Vec<u8>>();
+
+        for bytes in [0, 1, 8, 20, 32] {
+            let truncated = spec.hash_two_truncated(a, b, bytes);
+            assert_eq!(truncated, spec.hash_two_truncated(a, b, bytes));
+            assert_eq!(truncated[..], full[32 - bytes..]);
+        }
+        assert_ne!(
+            spec.hash_two_truncated(a, b, 20),
+            spec.hash_two_truncated(b, a, 20)
+        );
+    }
+
+    #[test]
+    fn test_commit_and_reveal() {
+        let spec = Spec::<Fr, 3, 2>::new(8, 57);
+        let (value, nonce) = (Fr::random(OsRng), Fr::random(OsRng));
+        let commitment = spec.commit_value(value, nonce);
+
+        assert!(spec.reveal_check(commitment, value, nonce));
+        assert!(!spec.reveal_check(commitment, value + Fr::one(), nonce));
+        assert!(!spec.reveal_check(commitment, value, nonce + Fr::one()));
+        assert!(!spec.reveal_check(commitment, nonce, value));
+        assert_ne!(commitment, spec.commit_value(value, Fr::random(OsRng)));
+    }
+
+    #[test]
+    fn test_verify_chain() {
+        let spec = Spec::<Fr, 3, 2>::new(8, 57);
+        let nonces = [Fr::random(OsRng), Fr::random(OsRng), Fr::random(OsRng)];
+        let mut openings = vec![];
+        let mut value = Fr::random(OsRng);
+        for nonce in nonces {
+            openings.push((value, nonce));
+            value = spec.commit_value(value, nonce);
+        }
+        let final_commitment = value;
+        assert!(spec.verify_chain(final_commitment, &openings));
+        assert!(!spec.verify_chain(final_commitment + Fr::one(), &openings));
+        assert!(!spec.verify_chain(final_commitment, &openings[..2]));
+        assert!(!spec.verify_chain(final_commitment, &[]));
+
+        // Tampering with any value or nonce
+        for level in 0..openings.len() {
+            let mut tampered = openings.clone();
+            tampered[level].0 += Fr::one();
+            assert!(!spec.verify_chain(final_commitment, &tampered));
+            let mut tampered = openings.clone();
+            tampered[level].1 += Fr::one();
+            assert!(!spec.verify_chain(final_commitment, &tampered));
+        }
+    }
+
+    #[test]
+    fn test_deterministic_nonce() {
+        let spec = Spec::<Fr, 3, 2>::new(8, 57);
+        let private_key = Fr::random(OsRng);
+        let message_hash = Fr::random(OsRng);
+
+        let nonce = spec.deterministic_nonce(private_key, message_hash);
+        assert_eq!(nonce, spec.deterministic_nonce(private_key, message_hash));
+        assert_ne!(nonce, spec.hash_two(private_key, message_hash));
+        assert_ne!(nonce, spec.accumulate_set(private_key, message_hash));
+        assert_ne!(
+            nonce,
+            spec.deterministic_nonce(private_key, message_hash + Fr::one())
+        );
+        assert_ne!(nonce, spec.deterministic_nonce(message_hash, private_key));
+    }
+
+    #[test]
+    fn test_set_digest() {
+        let spec = Spec::<Fr, 3, 2>::new(8, 57);
+        let mut elements = (0..6).map(|_| Fr::random(OsRng)).collect::<Vec<Fr>>();
+        let digest = spec.set_digest(&elements);
+
+        // Permutations of the set give the same digest
+        elements.reverse();
+        assert_eq!(spec.set_digest(&elements), digest);
+        elements.swap(0, 3);
+        elements.swap(1, 5);
+        assert_eq!(spec.set_digest(&elements), digest);
+
+        // Duplicates don't change the set
+        elements.push(elements[2]);
+        assert_eq!(spec.set_digest(&elements), digest);
+
+        // Digest is the fold over the sorted set
+        let (a, b) = (Fr::from(1), Fr::from(2));
+        let expected = spec.accumulate_set(spec.accumulate_set(Fr::zero(), a), b);
+        assert_eq!(spec.set_digest(&[b, a]), expected);
+        assert_ne!(spec.accumulate_set(a, b), spec.hash_two(a, b));
+
+        elements.push(Fr::random(OsRng));
+        assert_ne!(spec.set_digest(&elements), digest);
+        assert_eq!(spec.set_digest(&[]), Fr::zero());
+    }
 }
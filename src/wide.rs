@@ -0,0 +1,119 @@
+use core::marker::PhantomData;
+use core::ops::Mul;
+use digest::generic_array::ArrayLength;
+use digest::{FixedOutput, HashMarker, OutputSizeUser, Update};
+use halo2curves_axiom::group::ff::{FromUniformBytes, PrimeField};
+use typenum::{Prod, Unsigned};
+
+use crate::bytes::{write_be, ReprSize};
+use crate::Poseidon;
+
+/// `WidePoseidon` is a `digest` integration of `Poseidon` whose output is `N`
+/// squeezed elements instead of one, for example `U2` for a 64 byte output
+/// over a 32 byte field. Element size is given by `ReprSize`. Bytes are
+/// absorbed as in `Poseidon`. Output is the concatenation of big endian
+/// representations of the elements in the order they are returned by
+/// `Poseidon::squeeze_n`
+#[derive(Debug, Clone)]
+pub struct WidePoseidon<F: PrimeField, const T: usize, const RATE: usize, N> {
+    hasher: Poseidon<F, T, RATE>,
+    _output: PhantomData<N>,
+}
+
+impl<F: FromUniformBytes<64>, const T: usize, const RATE: usize, N> WidePoseidon<F, T, RATE, N> {
+    /// Constructs a clear state instance
+    pub fn new(r_f: usize, r_p: usize) -> Self {
+        Self::from_hasher(Poseidon::new(r_f, r_p))
+    }
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize, N> WidePoseidon<F, T, RATE, N> {
+    /// Wraps a hasher keeping the inputs it has already absorbed
+    pub fn from_hasher(hasher: Poseidon<F, T, RATE>) -> Self {
+        Self {
+            hasher,
+            _output: PhantomData,
+        }
+    }
+}
+
+impl<F: FromUniformBytes<64>, const T: usize, const RATE: usize, N> Default
+    for WidePoseidon<F, T, RATE, N>
+{
+    fn default() -> Self {
+        Self::from_hasher(Poseidon::default())
+    }
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize, N> HashMarker
+    for WidePoseidon<F, T, RATE, N>
+{
+}
+
+impl<F: ReprSize, const T: usize, const RATE: usize, N> OutputSizeUser
+    for WidePoseidon<F, T, RATE, N>
+where
+    N: Unsigned + Mul<F::Size>,
+    Prod<N, F::Size>: ArrayLength<u8> + 'static,
+{
+    type OutputSize = Prod<N, F::Size>;
+}
+
+impl<F: PrimeField, const T: usize, const RATE: usize, N> Update for WidePoseidon<F, T, RATE, N> {
+    fn update(&mut self, data: &[u8]) {
+        Update::update(&mut self.hasher, data);
+    }
+}
+
+impl<F: ReprSize, const T: usize, const RATE: usize, N> FixedOutput for WidePoseidon<F, T, RATE, N>
+where
+    N: Unsigned + Mul<F::Size>,
+    Prod<N, F::Size>: ArrayLength<u8> + 'static,
+{
+    fn finalize_into(mut self, out: &mut digest::Output<Self>) {
+        self.hasher.finish_bytes();
+        let elements = self.hasher.squeeze_n(N::USIZE);
+        for (chunk, element) in out.chunks_mut(F::Size::USIZE).zip(elements.iter()) {
+            write_be(element, chunk);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::WidePoseidon;
+    use crate::bytes::pack_bytes;
+    use crate::Poseidon;
+    use digest::{FixedOutput, Update};
+    use halo2curves_axiom::bn256::Fr;
+    use halo2curves_axiom::group::ff::PrimeField;
+    use typenum::{U1, U2};
+
+    #[test]
+    fn test_wide_output() {
+        let bytes = (0..100).map(|i| i as u8).collect::<Vec<u8>>();
+        let to_bytes = |e: &Fr| e.to_repr().iter().rev().copied().collect::<Vec<u8>>();
+
+        let mut hasher = WidePoseidon::<Fr, 5, 4, U2>::new(8, 57);
+        Update::update(&mut hasher, &bytes);
+        let output = hasher.finalize_fixed();
+        assert_eq!(output.len(), 64);
+
+        let mut expected = Poseidon::<Fr, 5, 4>::new(8, 57);
+        expected.update(&pack_bytes(&bytes));
+        expected.update(&[Fr::from(bytes.len() as u64)]);
+        let elements = expected.squeeze_n(2);
+        assert_eq!(output[..32], to_bytes(&elements[0])[..]);
+        assert_eq!(output[32..], to_bytes(&elements[1])[..]);
+
+        // Single element output is the same as `Poseidon`
+        let mut narrow = WidePoseidon::<Fr, 5, 4, U1>::new(8, 57);
+        Update::update(&mut narrow, &bytes);
+        let mut poseidon = Poseidon::<Fr, 5, 4>::new(8, 57);
+        Update::update(&mut poseidon, &bytes);
+        assert_eq!(
+            narrow.finalize_fixed().to_vec(),
+            poseidon.finalize_fixed().to_vec()
+        );
+    }
+}